@@ -0,0 +1,24 @@
+//! Human-friendly durations for interval flags, e.g. `500ms`, `2s`, `1m`
+
+use std::time::Duration;
+
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let invalid = || format!("invalid duration '{value}' (expected e.g. 500ms, 2s or 1m)");
+
+    let number: u64 = number.parse().map_err(|_| invalid())?;
+    let duration = match unit {
+        "ms" => Duration::from_millis(number),
+        "s" => Duration::from_secs(number),
+        "m" => Duration::from_secs(number.saturating_mul(60)),
+        _ => return Err(invalid()),
+    };
+
+    if duration.is_zero() {
+        return Err(format!("duration must be greater than zero, got '{value}'"));
+    }
+    Ok(duration)
+}
@@ -0,0 +1,124 @@
+//! Flag parsing for the default "run checks" mode
+
+use std::path::PathBuf;
+
+use crate::runner::{RunOptions, parse_concurrency, parse_deadline_ms};
+use crate::server::TokenSource;
+use crate::status::ExitCodes;
+
+pub const DEFAULT_CONFIG_PATH: &str = "healthcheck.config";
+
+/// Parse `[OPTIONS] [CONFIG_FILE]` for the default run mode
+///
+/// Flags may appear before or after the config path. The first positional
+/// argument is the config path; anything else is rejected.
+pub(super) fn parse_run_args(args: &[String]) -> Result<(String, RunOptions), String> {
+    let mut options = RunOptions::default();
+    let mut config_path = None;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--concurrency" => {
+                let value = flag_value(&mut iter, "--concurrency")?;
+                options.concurrency = Some(parse_concurrency(value)?);
+            }
+            "--deadline-ms" => {
+                let value = flag_value(&mut iter, "--deadline-ms")?;
+                options.deadline = Some(parse_deadline_ms(value)?);
+            }
+            "--exit-codes" => {
+                let value = flag_value(&mut iter, "--exit-codes")?;
+                options.exit_codes = Some(ExitCodes::parse(value)?);
+            }
+            "--tag" => {
+                let value = flag_value(&mut iter, "--tag")?;
+                options.selection.tags.push(value.to_string());
+            }
+            "--only" => {
+                let value = flag_value(&mut iter, "--only")?;
+                options.selection.only.push(value.to_string());
+            }
+            "--skip" => {
+                let value = flag_value(&mut iter, "--skip")?;
+                options.selection.skip.push(value.to_string());
+            }
+            "--from" => {
+                let value = flag_value(&mut iter, "--from")?;
+                options.from = Some(parse_from(value)?);
+            }
+            "--token-file" => {
+                let path = flag_value(&mut iter, "--token-file")?;
+                set_from_token(&mut options, TokenSource::File(path.to_string()))?;
+            }
+            "--token-env" => {
+                let var = flag_value(&mut iter, "--token-env")?;
+                set_from_token(&mut options, TokenSource::Env(var.to_string()))?;
+            }
+            flag if flag.starts_with("--") => {
+                return Err(format!("Unknown flag: {}", flag));
+            }
+            path => {
+                if config_path.is_some() {
+                    return Err(format!("Unexpected argument: {}", path));
+                }
+                config_path = Some(path.to_string());
+            }
+        }
+    }
+
+    if options.from_token.is_some() && options.from.is_none() {
+        return Err("--token-file and --token-env need --from or serve".to_string());
+    }
+    if options.from.is_some() {
+        let runs_checks = options.concurrency.is_some()
+            || options.deadline.is_some()
+            || !options.selection.is_empty();
+        if runs_checks || config_path.is_some() {
+            return Err("--from only combines with --exit-codes".to_string());
+        }
+    }
+
+    let config_path = config_path.unwrap_or_else(|| DEFAULT_CONFIG_PATH.to_string());
+    Ok((config_path, options))
+}
+
+/// Socket path of `--from unix:PATH`
+fn parse_from(value: &str) -> Result<PathBuf, String> {
+    match value.strip_prefix("unix:") {
+        Some(path) if !path.is_empty() => Ok(PathBuf::from(path)),
+        _ => Err(format!("--from expects unix:<socket path>, got '{value}'")),
+    }
+}
+
+fn set_from_token(options: &mut RunOptions, source: TokenSource) -> Result<(), String> {
+    if options.from_token.is_some() {
+        return Err("only one of --token-file and --token-env may be given".to_string());
+    }
+    options.from_token = Some(source);
+    Ok(())
+}
+
+/// Config path for `validate`, defaulting like a normal run
+pub(super) fn parse_validate_args(args: &[String]) -> Result<String, String> {
+    match args {
+        [] => Ok(DEFAULT_CONFIG_PATH.to_string()),
+        [path] if !path.starts_with("--") => Ok(path.clone()),
+        _ => Err("validate takes a single config file argument".to_string()),
+    }
+}
+
+/// Take the value following a flag, rejecting missing values and other flags
+pub(super) fn flag_value<'a>(
+    iter: &mut impl Iterator<Item = &'a String>,
+    flag: &str,
+) -> Result<&'a str, String> {
+    match iter.next() {
+        Some(value) if !value.starts_with("--") => Ok(value),
+        Some(other) => Err(format!(
+            "{} flag value cannot be another flag: {}",
+            flag, other
+        )),
+        None => Err(format!("{} requires a value", flag)),
+    }
+}
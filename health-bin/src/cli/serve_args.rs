@@ -0,0 +1,80 @@
+//! Flag parsing for `serve`
+
+use super::duration::parse_duration;
+use super::run_args::{flag_value, parse_run_args};
+use crate::monitor::MAX_HISTORY;
+use crate::server::{Cidr, Listen, ServeOptions, TokenSource, parse_socket_mode};
+
+/// Parse `serve [--listen ADDR|unix:PATH] [--interval DURATION] [AUTH] [OPTIONS] [CONFIG_FILE]`
+///
+/// Serve-only flags are taken out first; everything else is parsed like the
+/// default run mode, so `--concurrency`, `--deadline-ms` and the selection
+/// flags work the same in both.
+pub(super) fn parse_serve_args(args: &[String]) -> Result<(String, ServeOptions), String> {
+    let mut options = ServeOptions::default();
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--listen" => {
+                options.listen = Listen::parse(flag_value(&mut iter, "--listen")?)?;
+            }
+            "--socket-mode" => {
+                options.socket_mode =
+                    Some(parse_socket_mode(flag_value(&mut iter, "--socket-mode")?)?);
+            }
+            "--interval" => {
+                options.interval = parse_duration(flag_value(&mut iter, "--interval")?)?;
+            }
+            "--drain" => {
+                options.drain = parse_duration(flag_value(&mut iter, "--drain")?)?;
+            }
+            "--token-file" => {
+                let path = flag_value(&mut iter, "--token-file")?;
+                set_token(&mut options, TokenSource::File(path.to_string()))?;
+            }
+            "--token-env" => {
+                let var = flag_value(&mut iter, "--token-env")?;
+                set_token(&mut options, TokenSource::Env(var.to_string()))?;
+            }
+            "--allow" => {
+                let cidr = Cidr::parse(flag_value(&mut iter, "--allow")?)?;
+                options.auth.allow.push(cidr);
+            }
+            "--public" => options.auth.public = true,
+            "--watch-config" => options.watch_config = true,
+            "--history" => {
+                let value = flag_value(&mut iter, "--history")?;
+                options.history = match value.parse() {
+                    Ok(n) if n <= MAX_HISTORY => n,
+                    _ => {
+                        return Err(format!(
+                            "history must be 0-{MAX_HISTORY} results, got '{value}'"
+                        ));
+                    }
+                };
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    if options.socket_mode.is_some() && !matches!(options.listen, Listen::Unix(_)) {
+        return Err("--socket-mode requires --listen unix:<path>".to_string());
+    }
+
+    let (config_path, run) = parse_run_args(&rest)?;
+    if run.from.is_some() {
+        return Err("--from cannot be used with serve".to_string());
+    }
+    options.run = run;
+    Ok((config_path, options))
+}
+
+fn set_token(options: &mut ServeOptions, source: TokenSource) -> Result<(), String> {
+    if options.auth.token.is_some() {
+        return Err("only one of --token-file and --token-env may be given".to_string());
+    }
+    options.auth.token = Some(source);
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use super::duration::parse_duration;
+use super::serve_args::parse_serve_args;
+use super::watch_args::parse_watch_args;
+use crate::server::{DEFAULT_INTERVAL, DEFAULT_LISTEN, Listen, TokenSource};
+use std::time::Duration;
+
+fn serve_args(args: &[&str]) -> Result<(String, crate::server::ServeOptions), String> {
+    let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    parse_serve_args(&args)
+}
+
+#[test]
+fn test_parse_duration_units() {
+    assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+    assert_eq!(parse_duration("1m"), Ok(Duration::from_secs(60)));
+
+    for invalid in ["", "2", "s", "0s", "1.5s", "-1s", "2h"] {
+        assert!(parse_duration(invalid).is_err(), "accepted '{invalid}'");
+    }
+}
+
+#[test]
+fn test_parse_serve_args_defaults() {
+    let (path, options) = serve_args(&[]).unwrap();
+    assert_eq!(path, "healthcheck.config");
+    assert_eq!(options.listen, Listen::Tcp(DEFAULT_LISTEN.to_string()));
+    assert_eq!(options.interval, DEFAULT_INTERVAL);
+}
+
+#[test]
+fn test_parse_serve_args_mixes_serve_and_run_flags() {
+    let (path, options) = serve_args(&[
+        "--listen",
+        "0.0.0.0:9000",
+        "my.conf",
+        "--interval",
+        "2s",
+        "--concurrency",
+        "3",
+        "--watch-config",
+        "--history",
+        "20",
+        "--drain",
+        "30s",
+    ])
+    .unwrap();
+    assert_eq!(path, "my.conf");
+    assert!(options.watch_config);
+    assert_eq!(options.history, 20);
+    assert_eq!(options.drain, Duration::from_secs(30));
+    assert_eq!(options.listen, Listen::Tcp("0.0.0.0:9000".to_string()));
+    assert_eq!(options.interval, Duration::from_secs(2));
+    assert_eq!(options.run.concurrency, Some(3));
+
+    assert!(serve_args(&["--listen"]).is_err());
+    assert!(serve_args(&["--interval", "soon"]).is_err());
+    assert!(serve_args(&["--history", "-1"]).is_err());
+    assert!(serve_args(&["--history", "10000"]).is_ok());
+    assert!(serve_args(&["--history", "10001"]).is_err());
+    assert!(serve_args(&["--history", "18446744073709551615"]).is_err());
+    assert!(serve_args(&["--bogus"]).is_err());
+}
+
+#[test]
+fn test_parse_serve_args_auth_flags() {
+    let (_, options) = serve_args(&[
+        "--token-file",
+        "/run/secrets/token",
+        "--allow",
+        "10.0.0.0/8",
+        "--allow",
+        "::1",
+        "--public",
+    ])
+    .unwrap();
+    assert_eq!(
+        options.auth.token,
+        Some(TokenSource::File("/run/secrets/token".to_string()))
+    );
+    assert_eq!(options.auth.allow.len(), 2);
+    assert!(options.auth.public);
+
+    assert!(serve_args(&["--token-file", "a", "--token-env", "B"]).is_err());
+    assert!(serve_args(&["--allow", "10.0.0.0/40"]).is_err());
+}
+
+#[test]
+fn test_parse_serve_args_unix_socket() {
+    let (_, options) = serve_args(&[
+        "--listen",
+        "unix:/run/healthcheck.sock",
+        "--socket-mode",
+        "600",
+    ])
+    .unwrap();
+    assert_eq!(options.listen, Listen::Unix("/run/healthcheck.sock".into()));
+    assert_eq!(options.socket_mode, Some(0o600));
+
+    assert!(serve_args(&["--listen", "unix:"]).is_err());
+    assert!(serve_args(&["--listen", "unix:/a", "--socket-mode", "999"]).is_err());
+    assert!(serve_args(&["--socket-mode", "660"]).is_err());
+    assert!(serve_args(&["--from", "unix:/a"]).is_err());
+}
+
+#[test]
+fn test_parse_watch_args() {
+    let args: Vec<String> = [
+        "config",
+        "--interval",
+        "500ms",
+        "--watch-config",
+        "--only",
+        "db",
+    ]
+    .iter()
+    .map(|a| a.to_string())
+    .collect();
+    let (path, options) = parse_watch_args(&args).unwrap();
+    assert_eq!(path, "config");
+    assert_eq!(options.interval, Duration::from_millis(500));
+    assert!(options.watch_config);
+    assert_eq!(options.run.selection.only, ["db"]);
+
+    let (_, defaults) = parse_watch_args(&[]).unwrap();
+    assert_eq!(defaults.interval, crate::watch::DEFAULT_INTERVAL);
+    assert!(!defaults.watch_config);
+    for invalid in [
+        &["--interval", "0s"][..],
+        &["--listen", "x"],
+        &["--from", "unix:/a"],
+    ] {
+        let args: Vec<String> = invalid.iter().map(|a| a.to_string()).collect();
+        assert!(parse_watch_args(&args).is_err(), "accepted {invalid:?}");
+    }
+}
@@ -1,5 +1,7 @@
 use super::*;
 
+use crate::server::TokenSource;
+
 #[test]
 fn test_parse_output_flag_normal() {
     let args = vec![
@@ -48,3 +50,95 @@ fn test_parse_output_flag_correct_index() {
     assert_eq!(result, Some("./bin".to_string()));
     assert_ne!(result, Some("generate-bin".to_string()));
 }
+
+fn run_args(args: &[&str]) -> Result<(String, RunOptions), String> {
+    let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    run_args::parse_run_args(&args)
+}
+
+#[test]
+fn test_parse_run_args_defaults() {
+    let (path, options) = run_args(&[]).unwrap();
+    assert_eq!(path, "healthcheck.config");
+    assert_eq!(options.concurrency, None);
+    assert_eq!(options.deadline, None);
+}
+
+#[test]
+fn test_parse_run_args_concurrency_any_position() {
+    let (path, options) = run_args(&["my.conf", "--concurrency", "3"]).unwrap();
+    assert_eq!(path, "my.conf");
+    assert_eq!(options.concurrency, Some(3));
+
+    let (path, options) = run_args(&["--concurrency", "2", "my.conf"]).unwrap();
+    assert_eq!(path, "my.conf");
+    assert_eq!(options.concurrency, Some(2));
+}
+
+#[test]
+fn test_parse_run_args_rejects_invalid_concurrency() {
+    assert!(run_args(&["--concurrency", "0"]).is_err());
+    assert!(run_args(&["--concurrency", "many"]).is_err());
+    assert!(run_args(&["--concurrency"]).is_err());
+}
+
+#[test]
+fn test_parse_run_args_unknown_flag() {
+    let err = run_args(&["--bogus"]).unwrap_err();
+    assert!(err.contains("Unknown flag"));
+}
+
+#[test]
+fn test_parse_run_args_deadline() {
+    let (_, options) = run_args(&["--deadline-ms", "2500", "my.conf"]).unwrap();
+    assert_eq!(
+        options.deadline,
+        Some(std::time::Duration::from_millis(2500))
+    );
+
+    assert!(run_args(&["--deadline-ms", "0"]).is_err());
+    assert!(run_args(&["--deadline-ms", "--concurrency"]).is_err());
+}
+
+#[test]
+fn test_parse_run_args_exit_codes() {
+    let (_, options) = run_args(&["--exit-codes", "nagios"]).unwrap();
+    assert_eq!(options.exit_codes, Some(crate::status::ExitCodes::Nagios));
+
+    assert!(run_args(&["--exit-codes", "posix"]).is_err());
+}
+
+#[test]
+fn test_parse_run_args_selection_flags_repeat() {
+    let (_, options) = run_args(&[
+        "--tag",
+        "readiness",
+        "--only",
+        "db",
+        "--only",
+        "http",
+        "--skip",
+        "cache",
+    ])
+    .unwrap();
+    assert_eq!(options.selection.tags, vec!["readiness"]);
+    assert_eq!(options.selection.only, vec!["db", "http"]);
+    assert_eq!(options.selection.skip, vec!["cache"]);
+
+    assert!(run_args(&["--tag"]).is_err());
+}
+
+#[test]
+fn test_parse_run_args_from_socket() {
+    let (_, options) =
+        run_args(&["--from", "unix:/run/hc.sock", "--exit-codes", "nagios"]).unwrap();
+    assert_eq!(options.from, Some("/run/hc.sock".into()));
+
+    assert!(run_args(&["--from", "/run/hc.sock"]).is_err());
+    assert!(run_args(&["--from", "unix:/run/hc.sock", "my.conf"]).is_err());
+    assert!(run_args(&["--from", "unix:/run/hc.sock", "--tag", "db"]).is_err());
+
+    let (_, options) = run_args(&["--from", "unix:/run/hc.sock", "--token-env", "HC"]).unwrap();
+    assert_eq!(options.from_token, Some(TokenSource::Env("HC".to_string())));
+    assert!(run_args(&["--token-file", "/run/hc.token"]).is_err());
+}
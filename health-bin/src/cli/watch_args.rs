@@ -0,0 +1,31 @@
+//! Flag parsing for `watch`
+
+use super::duration::parse_duration;
+use super::run_args::{flag_value, parse_run_args};
+use crate::watch::WatchOptions;
+
+/// Parse `watch [--interval DURATION] [--watch-config] [OPTIONS] [CONFIG_FILE]`
+///
+/// Like `serve`, the run flags apply to every round of checks.
+pub(super) fn parse_watch_args(args: &[String]) -> Result<(String, WatchOptions), String> {
+    let mut options = WatchOptions::default();
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--interval" => {
+                options.interval = parse_duration(flag_value(&mut iter, "--interval")?)?;
+            }
+            "--watch-config" => options.watch_config = true,
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    let (config_path, run) = parse_run_args(&rest)?;
+    if run.from.is_some() {
+        return Err("--from cannot be used with watch".to_string());
+    }
+    options.run = run;
+    Ok((config_path, options))
+}
@@ -1,9 +1,11 @@
-//! CLI command implementations: `generate-bin` and `generate-conf`.
+//! CLI command implementations: `generate-bin`, `generate-conf` and `validate`.
 //!
 //! Security: Path validation, TOCTOU prevention, TTY detection, credential warnings.
 
 mod generate_bin;
 mod generate_conf;
+mod validate;
 
 pub use generate_bin::execute as generate_bin;
 pub use generate_conf::execute as generate_conf;
+pub use validate::execute as validate;
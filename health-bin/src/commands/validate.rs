@@ -0,0 +1,50 @@
+//! Config dry-run command: report every problem without probing anything
+
+use std::fs;
+
+use healthcheck_core::config::{SETTINGS_TYPE, parse_config_lenient, split_settings};
+
+use crate::plan::{build_registry, plan_problems};
+use crate::runner::RunOptions;
+
+pub fn execute(config_path: &str) -> Result<(), String> {
+    let content = fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read config '{}': {}", config_path, e))?;
+
+    let (configs, mut problems) = parse_config_lenient(&content);
+
+    for settings in configs.iter().filter(|c| c.check_type == SETTINGS_TYPE) {
+        problems.extend(RunOptions::check_settings_line(settings).err());
+    }
+
+    let (_, checks) = split_settings(configs);
+    if checks.is_empty() && problems.is_empty() {
+        problems.push("no checks configured".to_string());
+    }
+    problems.extend(plan_problems(&checks, &build_registry()));
+
+    problems.sort_by_key(|problem| line_number(problem));
+
+    if problems.is_empty() {
+        println!("{}: OK ({} checks)", config_path, checks.len());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        println!("{}: {}", config_path, problem);
+    }
+    Err(format!(
+        "{} problem(s) found in '{}'",
+        problems.len(),
+        config_path
+    ))
+}
+
+/// Line a problem refers to, so output follows the file; others go last
+fn line_number(problem: &str) -> usize {
+    problem
+        .strip_prefix("line ")
+        .and_then(|rest| rest.split_once(':'))
+        .and_then(|(line, _)| line.parse().ok())
+        .unwrap_or(usize::MAX)
+}
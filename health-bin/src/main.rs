@@ -23,13 +23,27 @@
 //!
 //! # Generate example config
 //! healthcheck generate-conf
+//!
+//! # Serve /health from continuously refreshed checks
+//! healthcheck serve --listen 0.0.0.0:9000 healthcheck.config
+//!
+//! # Live table of check states in the terminal
+//! healthcheck watch --interval 2s healthcheck.config
+//!
+//! # Check a config without probing anything
+//! healthcheck validate healthcheck.config
 //! ```
 
 mod cli;
 mod commands;
+mod monitor;
 mod path_validation;
+mod plan;
 mod runner;
+mod server;
+mod signals;
 mod status;
+mod watch;
 
 use cli::{CliAction, parse_args, print_help, print_version};
 
@@ -51,18 +65,35 @@ fn main() {
                 std::process::exit(1);
             }
         },
-        CliAction::Serve => {
-            eprintln!("Error: 'serve' command not yet implemented");
-            eprintln!("Coming soon: HTTP API server mode");
-            std::process::exit(1);
+        CliAction::Validate { config_path } => {
+            if let Err(e) = commands::validate(&config_path) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
         }
-        CliAction::Watch => {
-            eprintln!("Error: 'watch' command not yet implemented");
-            eprintln!("Coming soon: Continuous monitoring mode");
-            std::process::exit(1);
+        CliAction::Serve {
+            config_path,
+            options,
+        } => {
+            if let Err(e) = server::serve(&config_path, options) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        CliAction::Watch {
+            config_path,
+            options,
+        } => {
+            if let Err(e) = watch::watch(&config_path, options) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
         }
-        CliAction::RunChecks { config_path } => {
-            runner::run_health_checks(&config_path);
+        CliAction::RunChecks {
+            config_path,
+            options,
+        } => {
+            runner::run_health_checks(&config_path, options);
         }
     }
 }
@@ -0,0 +1,75 @@
+//! Check state transitions for subscribers such as `/events`
+
+use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use super::history::now_ms;
+use crate::status::CheckResult;
+
+/// Coarse state of a check, as reported in transitions
+pub fn state_name(result: Option<&CheckResult>) -> &'static str {
+    match result {
+        None => "pending",
+        Some(result) if result.skipped.is_some() => "skipped",
+        Some(result) if !result.ok => "failing",
+        Some(result) if result.warning.is_some() => "warning",
+        Some(_) => "ok",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    pub name: String,
+    /// `None` when replaying the current state to a new subscriber
+    pub old: Option<&'static str>,
+    pub new: &'static str,
+    pub error: Option<String>,
+    pub latency_ms: u64,
+    pub timestamp_ms: u64,
+}
+
+impl Transition {
+    pub fn new(name: &str, old: Option<&'static str>, result: Option<&CheckResult>) -> Self {
+        Self {
+            name: name.to_string(),
+            old,
+            new: state_name(result),
+            error: result.and_then(|r| r.error.clone().or_else(|| r.skipped.clone())),
+            latency_ms: result.map_or(0, |r| r.latency_ms),
+            timestamp_ms: now_ms(),
+        }
+    }
+}
+
+/// Fan-out of transitions to every live subscriber
+#[derive(Default)]
+pub struct Events {
+    subscribers: Mutex<Vec<Sender<Transition>>>,
+}
+
+impl Events {
+    pub fn subscribe(&self) -> Receiver<Transition> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(tx);
+        rx
+    }
+
+    /// Send to every subscriber, forgetting those that went away
+    pub fn publish(&self, transition: Transition) {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|tx| tx.send(transition.clone()).is_ok());
+    }
+
+    /// Drop every subscriber, ending their streams
+    pub fn close(&self) {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
@@ -0,0 +1,86 @@
+//! Bounded in-memory history of each check's recent results
+
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::status::CheckResult;
+
+/// Results kept per check without `--history`
+pub const DEFAULT_HISTORY: usize = 100;
+/// Upper bound for `--history`
+pub const MAX_HISTORY: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// When the run was recorded, in milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    pub ok: bool,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+    pub skipped: Option<String>,
+}
+
+impl HistoryEntry {
+    pub fn now(result: &CheckResult) -> Self {
+        Self {
+            timestamp_ms: now_ms(),
+            ok: result.ok,
+            latency_ms: result.latency_ms,
+            error: result.error.clone(),
+            skipped: result.skipped.clone(),
+        }
+    }
+}
+
+/// Current time in milliseconds since the Unix epoch
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64)
+}
+
+/// Ring buffer of the last `capacity` results, oldest first
+#[derive(Debug, Clone)]
+pub struct History {
+    entries: VecDeque<HistoryEntry>,
+    capacity: usize,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_HISTORY)
+    }
+}
+
+impl History {
+    /// Room for `capacity` results; memory is only taken as results arrive
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity.min(DEFAULT_HISTORY)),
+            capacity,
+        }
+    }
+
+    /// Append `entry`, dropping the oldest one when full
+    pub fn push(&mut self, entry: HistoryEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
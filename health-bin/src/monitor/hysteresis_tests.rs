@@ -0,0 +1,100 @@
+use super::*;
+use crate::plan::Hysteresis;
+use crate::runner::test_support::check_result;
+use crate::status::{CheckResult, OverallStatus, ReportEntry, Streak, render_results};
+
+fn result(ok: bool) -> CheckResult {
+    CheckResult {
+        name: "db".to_string(),
+        error: (!ok).then(|| "refused".to_string()),
+        ..check_result(ok)
+    }
+}
+
+fn state(rise: u32, fall: u32) -> CheckState {
+    CheckState {
+        hysteresis: Hysteresis { rise, fall },
+        ..CheckState::with_history(10)
+    }
+}
+
+fn reported(state: &CheckState) -> &'static str {
+    state_name(state.latest.as_ref())
+}
+
+#[test]
+fn test_fall_holds_a_passing_check_until_enough_failures() {
+    let mut state = state(1, 3);
+    state.record(result(true));
+    state.record(result(false));
+    state.record(result(false));
+    assert_eq!(reported(&state), "ok");
+    // The absorbed failure is still visible
+    assert_eq!(
+        state.latest.as_ref().unwrap().error.as_deref(),
+        Some("refused")
+    );
+
+    state.record(result(false));
+    assert_eq!(reported(&state), "failing");
+    assert_eq!(state.failures, 3);
+    assert!(state.history.entries().skip(1).all(|entry| !entry.ok));
+}
+
+#[test]
+fn test_rise_holds_a_failing_check_until_enough_successes() {
+    let mut state = state(2, 1);
+    state.record(result(false));
+    state.record(result(true));
+    assert_eq!(reported(&state), "failing");
+    let error = state.latest.as_ref().unwrap().error.clone();
+    assert_eq!(
+        error.as_deref(),
+        Some("recovering: 1 of rise=2 passing runs")
+    );
+
+    state.record(result(true));
+    assert_eq!(reported(&state), "ok");
+
+    // A failure in between starts the count again
+    state.record(result(false));
+    state.record(result(true));
+    state.record(result(false));
+    assert_eq!(reported(&state), "failing");
+}
+
+#[test]
+fn test_skips_do_not_break_streaks() {
+    let mut state = state(1, 2);
+    state.record(result(true));
+    state.record(result(false));
+    state.record(CheckResult {
+        skipped: Some("dependency down".to_string()),
+        ..result(false)
+    });
+    state.record(result(false));
+    assert_eq!(reported(&state), "failing");
+}
+
+#[test]
+fn test_streak_is_reported_with_the_result() {
+    let mut state = state(2, 3);
+    state.record(result(true));
+    state.record(result(false));
+
+    let latest = state.latest.clone().unwrap();
+    assert_eq!(
+        latest.streak,
+        Some(Streak {
+            successes: 0,
+            failures: 1,
+            rise: 2,
+            fall: 3
+        })
+    );
+    let json = render_results(&[ReportEntry::Check(latest)], OverallStatus::Healthy);
+    assert!(
+        json.contains("\"streak\": {\"successes\": 0, \"failures\": 1, \"rise\": 2, \"fall\": 3},"),
+        "{json}"
+    );
+}
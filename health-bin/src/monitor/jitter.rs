@@ -0,0 +1,36 @@
+//! Random delays that spread checks sharing an interval
+//!
+//! xorshift64 is plenty for de-synchronising probes and avoids a `rand`
+//! dependency.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub struct Jitter(u64);
+
+impl Jitter {
+    /// Seed from the clock, mixed with `salt` so checks started in the
+    /// same instant still diverge
+    pub fn seeded(salt: u64) -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        Self((nanos ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15)) | 1)
+    }
+
+    /// Uniformly random duration in `[0, max]`, at millisecond resolution
+    pub fn up_to(&mut self, max: Duration) -> Duration {
+        let max_ms = u64::try_from(max.as_millis()).unwrap_or(u64::MAX);
+        if max_ms == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(self.next() % max_ms.saturating_add(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
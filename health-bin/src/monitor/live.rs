@@ -0,0 +1,135 @@
+//! The current monitor of a long-running mode, replaced on config reload
+//!
+//! A reload re-reads the config and builds a complete new plan. If loading
+//! fails or any check cannot be created, the running checks stay as they
+//! are. Otherwise the new monitor takes over the state of every check whose
+//! name is unchanged, its schedulers start and the old ones stop.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use log::info;
+
+use super::{CheckState, Monitor, SchedulerOptions, spawn_scheduler};
+use crate::runner::{Loaded, RunOptions, load_checks};
+
+pub struct Live {
+    config_path: String,
+    /// CLI options; every load merges the config's `settings:` into a copy
+    cli: RunOptions,
+    interval: Duration,
+    /// Results kept per check
+    history: usize,
+    current: RwLock<Arc<Monitor>>,
+    /// Set by `stop`; later reloads are refused
+    stopped: AtomicBool,
+}
+
+impl Live {
+    /// Load the config and start scheduling its checks
+    pub fn start(
+        config_path: &str,
+        cli: RunOptions,
+        interval: Duration,
+        history: usize,
+    ) -> Result<Self, String> {
+        let (loaded, scheduler) = load(config_path, &cli, interval)?;
+        let monitor = Arc::new(Monitor::new(loaded, history));
+        spawn_scheduler(Arc::clone(&monitor), scheduler);
+
+        Ok(Self {
+            config_path: config_path.to_string(),
+            cli,
+            interval,
+            history,
+            current: RwLock::new(monitor),
+            stopped: AtomicBool::new(false),
+        })
+    }
+
+    pub fn config_path(&self) -> &str {
+        &self.config_path
+    }
+
+    /// The monitor of the checks currently scheduled
+    pub fn monitor(&self) -> Arc<Monitor> {
+        Arc::clone(&self.current.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Replace the running checks with the config's current contents
+    ///
+    /// On error the current checks keep running unchanged.
+    pub fn reload(&self) -> Result<(), String> {
+        let (loaded, scheduler) = load(&self.config_path, &self.cli, self.interval)?;
+        if let Some(check) = loaded.plan.iter().find(|check| check.probe.is_err()) {
+            let e = check.probe.as_ref().err().map_or("", String::as_str);
+            return Err(format!("line {}: {e}", check.line));
+        }
+
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        if self.stopped.load(Ordering::SeqCst) {
+            return Err("shutting down".to_string());
+        }
+        let old = Arc::clone(&current);
+        let mut monitor = Monitor::new(loaded, self.history);
+        monitor.inherit(&old);
+        let monitor = Arc::new(monitor);
+        spawn_scheduler(Arc::clone(&monitor), scheduler);
+
+        *current = Arc::clone(&monitor);
+        drop(current);
+        old.retirement.retire();
+        info!(
+            "Reloaded {} checks from {}",
+            monitor.plan().len(),
+            self.config_path
+        );
+        Ok(())
+    }
+
+    /// Stop scheduling checks and wait for runs in flight to finish
+    pub fn stop(&self) {
+        let current = self.current.read().unwrap_or_else(|e| e.into_inner());
+        self.stopped.store(true, Ordering::SeqCst);
+        current.retirement.retire();
+        for lock in &current.run_locks {
+            drop(lock.lock());
+        }
+    }
+}
+
+fn load(
+    config_path: &str,
+    cli: &RunOptions,
+    interval: Duration,
+) -> Result<(Loaded, SchedulerOptions), String> {
+    let mut run = cli.clone();
+    let loaded = load_checks(config_path, &mut run)?;
+    let scheduler = SchedulerOptions {
+        interval,
+        concurrency: run.concurrency(),
+        deadline: run.deadline,
+    };
+    Ok((loaded, scheduler))
+}
+
+impl Monitor {
+    /// Take over the state and run lock of every check of `old` whose name
+    /// is unchanged, and its event subscribers
+    fn inherit(&mut self, old: &Monitor) {
+        let old_states = old.states();
+        let states = self.states.get_mut().unwrap_or_else(|e| e.into_inner());
+
+        for (idx, check) in self.plan.iter().enumerate() {
+            if let Some(old_idx) = old.plan.iter().position(|c| c.name == check.name) {
+                states[idx] = CheckState {
+                    hysteresis: states[idx].hysteresis,
+                    ..old_states[old_idx].clone()
+                };
+                self.run_locks[idx] = Arc::clone(&old.run_locks[old_idx]);
+            }
+        }
+        self.events = Arc::clone(&old.events);
+    }
+}
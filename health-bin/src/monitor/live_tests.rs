@@ -0,0 +1,77 @@
+use super::*;
+use crate::runner::RunOptions;
+use std::env;
+use std::fs;
+use std::time::Duration;
+
+/// Checks that never run on their own during a test
+const IDLE: &str = "host=127.0.0.1,port=1,initial_delay_ms=600000";
+
+fn config_file(name: &str, content: &str) -> String {
+    let mut path = env::temp_dir();
+    path.push(format!("live_{name}_{}.conf", std::process::id()));
+    fs::write(&path, content).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+fn start(path: &str) -> Live {
+    let interval = Duration::from_secs(60);
+    Live::start(path, RunOptions::default(), interval, DEFAULT_HISTORY).unwrap()
+}
+
+#[test]
+fn test_reload_keeps_state_of_unchanged_names() {
+    let path = config_file("keep", &format!("tcp:name=a,{IDLE}\ntcp:name=b,{IDLE}\n"));
+    let live = start(&path);
+    let old = live.monitor();
+    old.record(0, Outcome::Skipped("before reload".to_string()));
+
+    fs::write(&path, format!("tcp:name=c,{IDLE}\ntcp:name=a,{IDLE}\n")).unwrap();
+    live.reload().unwrap();
+
+    let monitor = live.monitor();
+    let states = monitor.states();
+    assert_eq!(monitor.plan()[1].name, "a");
+    let kept = states[1].latest.as_ref().and_then(|r| r.skipped.as_deref());
+    assert_eq!(kept, Some("before reload"));
+    assert!(states[0].latest.is_none());
+    assert!(!old.retirement.sleep(Duration::from_secs(5)));
+
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_invalid_reload_keeps_current_checks() {
+    let path = config_file("invalid", &format!("tcp:name=a,{IDLE}\n"));
+    let live = start(&path);
+    let before = live.monitor();
+
+    for broken in ["tcp:name=a,port=not-a-port\n", "not a check line\n", ""] {
+        fs::write(&path, broken).unwrap();
+        assert!(live.reload().is_err(), "accepted {broken:?}");
+        assert!(Arc::ptr_eq(&before, &live.monitor()));
+    }
+
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_reload_does_not_rerun_checks_early() {
+    let check = "tcp:name=a,host=127.0.0.1,port=1,interval_ms=60000,jitter_ms=0\n";
+    let path = config_file("interval", check);
+    let live = start(&path);
+    let runs = |live: &Live| live.monitor().with_states(|states| states[0].runs);
+    for _ in 0..100 {
+        if runs(&live) > 0 {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(runs(&live), 1);
+
+    live.reload().unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+    assert_eq!(runs(&live), 1);
+
+    let _ = fs::remove_file(path);
+}
@@ -0,0 +1,139 @@
+//! Latest results of continuously running checks
+//!
+//! Long-running modes keep one `Monitor` per loaded config. The scheduler
+//! records every run here and readers (HTTP endpoints, notifiers) take
+//! consistent snapshots without waiting for checks in flight.
+
+mod events;
+mod history;
+mod jitter;
+mod live;
+mod on_demand;
+mod reload;
+mod retire;
+mod scheduler;
+mod slots;
+mod snapshot;
+mod state;
+
+pub use events::{Events, Transition, state_name};
+pub use history::{DEFAULT_HISTORY, History, MAX_HISTORY, now_ms};
+pub use live::Live;
+pub use reload::spawn_reload_watcher;
+pub use scheduler::{SchedulerOptions, spawn_scheduler};
+pub use snapshot::Snapshot;
+pub use state::{CheckState, LATENCY_BUCKETS};
+
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use crate::plan::{PlannedCheck, PlannedGroup};
+use crate::runner::{Loaded, Outcome, to_result};
+use on_demand::{Flights, Scheduling};
+use retire::Retirement;
+
+/// Error reported for checks that have not completed a run yet
+pub const PENDING: &str = "pending: not run yet";
+
+pub struct Monitor {
+    plan: Arc<Vec<PlannedCheck>>,
+    groups: Vec<PlannedGroup>,
+    states: RwLock<Vec<CheckState>>,
+    /// Held while a check runs; shared with the monitor replacing this one
+    /// so a reload never overlaps two runs of the same check
+    run_locks: Vec<Arc<Mutex<()>>>,
+    /// State transitions; the same bus is handed on across reloads
+    events: Arc<Events>,
+    flights: Flights,
+    /// Set by `spawn_scheduler`; on-demand runs share its limits
+    scheduling: OnceLock<Scheduling>,
+    retirement: Retirement,
+}
+
+impl Monitor {
+    /// Monitor of `loaded`, keeping the last `history` results per check
+    pub fn new(loaded: Loaded, history: usize) -> Self {
+        let states = loaded
+            .plan
+            .iter()
+            .map(|check| CheckState {
+                hysteresis: check.options.hysteresis,
+                ..CheckState::with_history(history)
+            })
+            .collect();
+        let run_locks = loaded.plan.iter().map(|_| Arc::default()).collect();
+        Self {
+            plan: Arc::new(loaded.plan),
+            groups: loaded.groups,
+            states: RwLock::new(states),
+            run_locks,
+            events: Arc::default(),
+            flights: Flights::default(),
+            scheduling: OnceLock::new(),
+            retirement: Retirement::default(),
+        }
+    }
+
+    pub fn plan(&self) -> &Arc<Vec<PlannedCheck>> {
+        &self.plan
+    }
+
+    pub fn events(&self) -> &Arc<Events> {
+        &self.events
+    }
+
+    /// Publish the outcome of one run of the check at plan index `idx`
+    pub fn record(&self, idx: usize, outcome: Outcome) {
+        let check = &self.plan[idx];
+        let result = to_result(check, outcome);
+        let Ok(mut states) = self.states.write() else {
+            return;
+        };
+
+        let old = state_name(states[idx].latest.as_ref());
+        states[idx].record(result);
+        let latest = states[idx].latest.as_ref();
+        let transition =
+            (old != state_name(latest)).then(|| Transition::new(&check.name, Some(old), latest));
+        drop(states);
+
+        if let Some(transition) = transition {
+            self.events.publish(transition);
+        }
+    }
+
+    /// Copy of the state of the check called `name`
+    pub fn state(&self, name: &str) -> Option<CheckState> {
+        let idx = self.plan.iter().position(|check| check.name == name)?;
+        let states = self.states.read().ok()?;
+        Some(states[idx].clone())
+    }
+
+    /// Run `f` over every check's state under the read lock, without copying
+    pub fn with_states<T>(&self, f: impl FnOnce(&[CheckState]) -> T) -> T {
+        let states = self.states.read().unwrap_or_else(|e| e.into_inner());
+        f(&states)
+    }
+
+    /// Copy of every check's state, in plan order
+    pub fn states(&self) -> Vec<CheckState> {
+        match self.states.read() {
+            Ok(states) => states.clone(),
+            Err(_) => vec![CheckState::default(); self.plan.len()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod scheduler_tests;
+
+#[cfg(test)]
+mod live_tests;
+
+#[cfg(test)]
+mod on_demand_tests;
+
+#[cfg(test)]
+mod hysteresis_tests;
@@ -0,0 +1,114 @@
+//! Running checks on request, outside their schedule
+//!
+//! Concurrent requests for the same check share one run (single flight):
+//! the first request probes and records the result, the others wait for
+//! it. An on-demand run still waits for a scheduled run of the same check
+//! in flight, takes a `--concurrency` slot and honours the deadline.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use super::scheduler::{Gate, dependency_gate, run_with_deadline};
+use super::slots::Slots;
+use super::{Monitor, Snapshot};
+use crate::runner::Outcome;
+use crate::status::CheckResult;
+
+/// Limits shared with the scheduler, set when it starts
+pub struct Scheduling {
+    pub slots: Arc<Slots>,
+    pub deadline: Option<Duration>,
+}
+
+/// On-demand runs in progress, by plan index
+#[derive(Default)]
+pub struct Flights(Mutex<HashMap<usize, Arc<Flight>>>);
+
+#[derive(Default)]
+struct Flight {
+    result: Mutex<Option<CheckResult>>,
+    landed: Condvar,
+}
+
+impl Flight {
+    fn land(&self, result: CheckResult) {
+        *self.result.lock().unwrap_or_else(|e| e.into_inner()) = Some(result);
+        self.landed.notify_all();
+    }
+
+    fn wait(&self) -> CheckResult {
+        let mut result = self.result.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            if let Some(result) = result.as_ref() {
+                return result.clone();
+            }
+            result = self.landed.wait(result).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+}
+
+impl Monitor {
+    /// Run the check at `idx` now, or join the on-demand run in progress
+    pub fn run_now(&self, idx: usize) -> CheckResult {
+        let (flight, leader) = {
+            let mut flights = self.flights.0.lock().unwrap_or_else(|e| e.into_inner());
+            match flights.get(&idx) {
+                Some(flight) => (Arc::clone(flight), false),
+                None => {
+                    let flight = Arc::new(Flight::default());
+                    flights.insert(idx, Arc::clone(&flight));
+                    (flight, true)
+                }
+            }
+        };
+
+        if leader {
+            let result = self.run_and_record(idx);
+            self.flights
+                .0
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&idx);
+            flight.land(result);
+        }
+        flight.wait()
+    }
+
+    /// Run every check now, each after its dependencies, and report
+    pub fn run_all_now(&self) -> Snapshot {
+        let plan = self.plan();
+        let mut done = vec![false; plan.len()];
+        while done.contains(&false) {
+            let wave: Vec<usize> = (0..plan.len())
+                .filter(|&idx| !done[idx] && plan[idx].deps.iter().all(|&dep| done[dep]))
+                .collect();
+            thread::scope(|scope| {
+                for &idx in &wave {
+                    scope.spawn(move || self.run_now(idx));
+                }
+            });
+            for idx in wave {
+                done[idx] = true;
+            }
+        }
+        self.snapshot()
+    }
+
+    fn run_and_record(&self, idx: usize) -> CheckResult {
+        let _running = self.run_locks[idx].lock();
+        match dependency_gate(self, idx) {
+            Gate::Skip(reason) => self.record(idx, Outcome::Skipped(reason)),
+            // A dependency without a result yet does not block a manual run
+            Gate::Run | Gate::Wait => {
+                let scheduling = self.scheduling.get();
+                let _slot = scheduling.map(|s| s.slots.take());
+                run_with_deadline(self, idx, scheduling.and_then(|s| s.deadline));
+            }
+        }
+
+        self.with_states(|states| states[idx].latest.clone())
+            .expect("a run was just recorded")
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::plan::CheckOptions;
+use crate::runner::Loaded;
+use crate::status::ReportEntry;
+use healthcheck_core::{probes::ProbeResult, registry::HealthCheck};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Counts its runs; each takes `delay`
+struct SlowCheck {
+    ok: bool,
+    delay: Duration,
+    runs: Arc<AtomicUsize>,
+}
+
+impl HealthCheck for SlowCheck {
+    fn check(&self) -> ProbeResult {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(self.delay);
+        match self.ok {
+            true => ProbeResult::success(1),
+            false => ProbeResult::failure(1, "down"),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "slow"
+    }
+}
+
+fn planned(name: &str, ok: bool, deps: Vec<usize>, runs: &Arc<AtomicUsize>) -> PlannedCheck {
+    PlannedCheck {
+        name: name.to_string(),
+        check_type: "slow".to_string(),
+        target: String::new(),
+        line: 1,
+        options: CheckOptions::default(),
+        probe: Ok(Box::new(SlowCheck {
+            ok,
+            delay: Duration::from_millis(100),
+            runs: Arc::clone(runs),
+        })),
+        deps,
+    }
+}
+
+fn monitor(plan: Vec<PlannedCheck>) -> Monitor {
+    let loaded = Loaded {
+        plan,
+        groups: Vec::new(),
+    };
+    Monitor::new(loaded, DEFAULT_HISTORY)
+}
+
+#[test]
+fn test_concurrent_requests_share_one_run() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let monitor = monitor(vec![planned("api", true, Vec::new(), &runs)]);
+
+    let results: Vec<_> = thread::scope(|scope| {
+        let requests: Vec<_> = (0..8).map(|_| scope.spawn(|| monitor.run_now(0))).collect();
+        requests.into_iter().map(|r| r.join().unwrap()).collect()
+    });
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    assert!(
+        results
+            .iter()
+            .all(|result| result.ok && result.name == "api")
+    );
+    assert_eq!(monitor.states()[0].runs, 1);
+
+    // A later request probes again
+    monitor.run_now(0);
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_run_all_now_runs_dependencies_first() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let monitor = monitor(vec![
+        planned("app", true, vec![1], &runs),
+        planned("db", false, Vec::new(), &runs),
+    ]);
+
+    let snapshot = monitor.run_all_now();
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    let skipped = snapshot.entries.iter().find_map(|entry| match entry {
+        ReportEntry::Check(result) if result.name == "app" => result.skipped.clone(),
+        _ => None,
+    });
+    assert_eq!(skipped.as_deref(), Some("dependency db failed"));
+}
@@ -0,0 +1,41 @@
+//! Triggering config reloads: SIGHUP and, optionally, file changes
+
+use std::fs;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use log::warn;
+
+use super::Live;
+use crate::signals::{self, Signal};
+
+/// How often the watcher looks for SIGHUP and a changed config file
+const RELOAD_POLL: Duration = Duration::from_millis(250);
+
+/// Reload `live` on SIGHUP and, with `watch_file`, whenever the config
+/// file's modification time changes
+pub fn spawn_reload_watcher(live: Arc<Live>, watch_file: bool) {
+    signals::install(Signal::Hangup);
+
+    let mut modified = modified_time(live.config_path());
+    thread::spawn(move || {
+        loop {
+            thread::sleep(RELOAD_POLL);
+            let mut reload = signals::take(Signal::Hangup);
+            if watch_file {
+                let now = modified_time(live.config_path());
+                reload |= now != modified;
+                modified = now;
+            }
+
+            if reload && let Err(e) = live.reload() {
+                warn!("Config reload failed, keeping the current checks: {e}");
+            }
+        }
+    });
+}
+
+fn modified_time(path: &str) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -0,0 +1,40 @@
+//! Stopping the scheduler threads of a replaced monitor
+
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Set once when a monitor is replaced; wakes its sleeping threads
+#[derive(Default)]
+pub struct Retirement {
+    retired: Mutex<bool>,
+    changed: Condvar,
+}
+
+impl Retirement {
+    pub fn retire(&self) {
+        *self.retired.lock().unwrap_or_else(|e| e.into_inner()) = true;
+        self.changed.notify_all();
+    }
+
+    pub fn is_retired(&self) -> bool {
+        *self.retired.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Sleep for `duration`; returns `false` early once retired
+    pub fn sleep(&self, duration: Duration) -> bool {
+        let until = Instant::now() + duration;
+        let mut retired = self.retired.lock().unwrap_or_else(|e| e.into_inner());
+        while !*retired {
+            let left = until.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return true;
+            }
+            retired = self
+                .changed
+                .wait_timeout(retired, left)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+        false
+    }
+}
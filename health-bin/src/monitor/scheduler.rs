@@ -0,0 +1,136 @@
+//! Per-check scheduling for long-running modes
+//!
+//! Every check gets its own thread that runs it, records the outcome and
+//! sleeps until its next run, so a run never overlaps the previous run of
+//! the same check. `--concurrency` still bounds how many checks probe at
+//! the same time across all threads. The threads of a monitor replaced by
+//! a config reload stop at their next sleep.
+
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use healthcheck_core::probes::elapsed_ms;
+
+use super::Monitor;
+use super::jitter::Jitter;
+use super::on_demand::Scheduling;
+use super::slots::Slots;
+use crate::runner::{Outcome, abandoned};
+
+/// How often a check whose dependencies have no result yet looks again
+const DEPENDENCY_POLL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SchedulerOptions {
+    /// Interval for checks without `interval_ms=`
+    pub interval: Duration,
+    pub concurrency: usize,
+    /// Longest a single run may take before it is reported as failed
+    pub deadline: Option<Duration>,
+}
+
+/// Start one scheduling thread per check of the monitor's plan
+pub fn spawn_scheduler(monitor: Arc<Monitor>, options: SchedulerOptions) {
+    let slots = Arc::new(Slots::new(options.concurrency));
+    let _ = monitor.scheduling.set(Scheduling {
+        slots: Arc::clone(&slots),
+        deadline: options.deadline,
+    });
+
+    for idx in 0..monitor.plan().len() {
+        let monitor = Arc::clone(&monitor);
+        let slots = Arc::clone(&slots);
+        thread::spawn(move || schedule_check(&monitor, idx, options, &slots));
+    }
+}
+
+fn schedule_check(monitor: &Monitor, idx: usize, options: SchedulerOptions, slots: &Slots) {
+    let schedule = monitor.plan()[idx].options.schedule;
+    let interval = schedule.interval_or(options.interval);
+    let jitter = schedule.jitter_for(interval);
+    let mut random = Jitter::seeded(idx as u64);
+
+    // A check carried over by a reload keeps the interval it was on
+    let since_last = monitor.with_states(|states| states[idx].last_run.map(|at| at.elapsed()));
+    let initial_delay = match since_last {
+        Some(elapsed) => interval.saturating_sub(elapsed),
+        None => schedule.initial_delay,
+    };
+
+    let mut pause = initial_delay.saturating_add(random.up_to(jitter));
+    while monitor.retirement.sleep(pause) {
+        let start = Instant::now();
+        match dependency_gate(monitor, idx) {
+            Gate::Wait => {
+                pause = DEPENDENCY_POLL;
+                continue;
+            }
+            Gate::Skip(reason) => monitor.record(idx, Outcome::Skipped(reason)),
+            Gate::Run => {
+                let _running = monitor.run_locks[idx].lock();
+                if monitor.retirement.is_retired() {
+                    break;
+                }
+                let _slot = slots.take();
+                run_with_deadline(monitor, idx, options.deadline);
+            }
+        }
+        pause = interval
+            .saturating_sub(start.elapsed())
+            .saturating_add(random.up_to(jitter));
+    }
+}
+
+pub(super) enum Gate {
+    Run,
+    /// A dependency has not finished its first run
+    Wait,
+    Skip(String),
+}
+
+/// Run only when every dependency passed its latest run
+pub(super) fn dependency_gate(monitor: &Monitor, idx: usize) -> Gate {
+    let plan = monitor.plan();
+
+    monitor.with_states(|states| {
+        for &dep in &plan[idx].deps {
+            let verb = match &states[dep].latest {
+                None => return Gate::Wait,
+                Some(result) if result.skipped.is_some() => "skipped",
+                Some(result) if !result.ok => "failed",
+                Some(_) => continue,
+            };
+            return Gate::Skip(format!("dependency {} {verb}", plan[dep].name));
+        }
+        Gate::Run
+    })
+}
+
+/// Run the check and record its outcome
+///
+/// A run exceeding the deadline is recorded as failed right away, but the
+/// next run of the check waits until the hung one returns.
+pub(super) fn run_with_deadline(monitor: &Monitor, idx: usize, deadline: Option<Duration>) {
+    let plan = monitor.plan();
+    let Some(deadline) = deadline else {
+        monitor.record(idx, Outcome::Completed(plan[idx].run()));
+        return;
+    };
+
+    let start = Instant::now();
+    let (done_tx, done_rx) = mpsc::channel();
+    let jobs = Arc::clone(plan);
+    thread::spawn(move || {
+        let _ = done_tx.send(jobs[idx].run());
+    });
+
+    match done_rx.recv_timeout(deadline) {
+        Ok(attempted) => monitor.record(idx, Outcome::Completed(attempted)),
+        Err(_) => {
+            monitor.record(idx, abandoned(&plan[idx], elapsed_ms(start)));
+            let _ = done_rx.recv();
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::*;
+use crate::plan::CheckOptions;
+use crate::runner::Loaded;
+use healthcheck_core::{probes::ProbeResult, registry::HealthCheck};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Sleeps for `delay`, tracking how many runs overlap
+struct CountingCheck {
+    delay: Duration,
+    ok: bool,
+    running: Arc<AtomicUsize>,
+    max_running: Arc<AtomicUsize>,
+}
+
+impl HealthCheck for CountingCheck {
+    fn check(&self) -> ProbeResult {
+        let now = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_running.fetch_max(now, Ordering::SeqCst);
+        thread::sleep(self.delay);
+        self.running.fetch_sub(1, Ordering::SeqCst);
+        match self.ok {
+            true => ProbeResult::success(self.delay.as_millis() as u64),
+            false => ProbeResult::failure(0, "down"),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "counting"
+    }
+}
+
+fn planned(name: &str, delay_ms: u64, ok: bool, max_running: &Arc<AtomicUsize>) -> PlannedCheck {
+    let mut options = CheckOptions::default();
+    options.schedule.interval = Some(Duration::from_millis(20));
+    options.schedule.jitter = Some(Duration::ZERO);
+    PlannedCheck {
+        name: name.to_string(),
+        check_type: "counting".to_string(),
+        target: String::new(),
+        line: 1,
+        options,
+        probe: Ok(Box::new(CountingCheck {
+            delay: Duration::from_millis(delay_ms),
+            ok,
+            running: Arc::new(AtomicUsize::new(0)),
+            max_running: Arc::clone(max_running),
+        })),
+        deps: Vec::new(),
+    }
+}
+
+fn start(plan: Vec<PlannedCheck>, deadline: Option<Duration>) -> Arc<Monitor> {
+    let loaded = Loaded {
+        plan,
+        groups: Vec::new(),
+    };
+    let monitor = Arc::new(Monitor::new(loaded, DEFAULT_HISTORY));
+    spawn_scheduler(
+        Arc::clone(&monitor),
+        SchedulerOptions {
+            interval: Duration::from_secs(60),
+            concurrency: 4,
+            deadline,
+        },
+    );
+    monitor
+}
+
+#[test]
+fn test_scheduler_reruns_checks_on_their_interval_without_overlap() {
+    let max_running = Arc::new(AtomicUsize::new(0));
+    // Runs take longer than the 20ms interval
+    let monitor = start(vec![planned("slow", 50, true, &max_running)], None);
+
+    thread::sleep(Duration::from_millis(300));
+    let runs = monitor.states()[0].runs;
+    assert!(runs >= 3, "only {runs} runs");
+    assert_eq!(max_running.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_scheduler_skips_dependents_of_failing_checks() {
+    let max_running = Arc::new(AtomicUsize::new(0));
+    let mut dependent = planned("api", 1, true, &max_running);
+    dependent.deps = vec![0];
+    let monitor = start(vec![planned("db", 1, false, &max_running), dependent], None);
+
+    thread::sleep(Duration::from_millis(150));
+    let states = monitor.states();
+    let latest = states[1].latest.as_ref().expect("dependent recorded");
+    assert_eq!(latest.skipped.as_deref(), Some("dependency db failed"));
+    assert_eq!(states[1].runs, 0);
+}
+
+#[test]
+fn test_scheduler_reports_hung_runs_at_the_deadline() {
+    let max_running = Arc::new(AtomicUsize::new(0));
+    let monitor = start(
+        vec![planned("hung", 400, true, &max_running)],
+        Some(Duration::from_millis(50)),
+    );
+
+    thread::sleep(Duration::from_millis(150));
+    let states = monitor.states();
+    let latest = states[0].latest.as_ref().expect("deadline recorded");
+    assert_eq!(latest.error.as_deref(), Some("deadline exceeded"));
+    // The hung run is still going, so no second run has started
+    assert_eq!(states[0].runs, 1);
+    assert_eq!(max_running.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,68 @@
+//! Reports assembled from the latest results
+
+use super::{Monitor, PENDING};
+use crate::plan::{PlannedCheck, ProbeClass};
+use crate::runner::assemble_report;
+use crate::status::{CheckResult, OverallStatus, ReportEntry};
+
+/// Report built from the latest result of every check
+pub struct Snapshot {
+    pub entries: Vec<ReportEntry>,
+    pub status: OverallStatus,
+}
+
+impl Monitor {
+    pub fn snapshot(&self) -> Snapshot {
+        let results = self
+            .plan
+            .iter()
+            .zip(self.states())
+            .map(|(check, state)| state.latest.unwrap_or_else(|| pending(check)))
+            .collect();
+
+        let entries = assemble_report(&self.plan, &self.groups, results);
+        let status = OverallStatus::from_entries(&entries);
+        Snapshot { entries, status }
+    }
+
+    /// Snapshot of only the checks counting for `class`
+    ///
+    /// A group is included as a whole when any of its members counts.
+    pub fn probe_snapshot(&self, class: ProbeClass) -> Snapshot {
+        let counts = |name: &str| {
+            self.plan
+                .iter()
+                .any(|check| check.name == name && check.options.probes.contains(class))
+        };
+
+        let entries: Vec<ReportEntry> = self
+            .snapshot()
+            .entries
+            .into_iter()
+            .filter(|entry| match entry {
+                ReportEntry::Check(result) => counts(&result.name),
+                ReportEntry::Group(group) => group.members.iter().any(|m| counts(&m.name)),
+            })
+            .collect();
+        let status = OverallStatus::from_entries(&entries);
+        Snapshot { entries, status }
+    }
+}
+
+/// Placeholder result for a check that has not run yet; it counts as failed
+fn pending(check: &PlannedCheck) -> CheckResult {
+    CheckResult {
+        name: check.name.clone(),
+        check_type: check.check_type.clone(),
+        target: check.target.clone(),
+        severity: check.options.severity,
+        ok: false,
+        latency_ms: 0,
+        error: Some(PENDING.to_string()),
+        attempts: 0,
+        attempt_errors: Vec::new(),
+        skipped: None,
+        warning: None,
+        streak: None,
+    }
+}
@@ -0,0 +1,121 @@
+//! Per-check state kept across runs
+
+use std::time::Instant;
+
+use super::events::state_name;
+use super::history::{History, HistoryEntry};
+use crate::plan::Hysteresis;
+use crate::status::{CheckResult, Streak};
+
+/// Upper bounds of the latency histogram buckets, in seconds
+pub const LATENCY_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct CheckState {
+    /// Result of the most recent run as reported, after `rise=`/`fall=`;
+    /// `None` until the first one finishes
+    pub latest: Option<CheckResult>,
+    /// Completed runs, not counting skips
+    pub runs: u64,
+    pub failures: u64,
+    /// Passing runs since the last failing one
+    pub consecutive_successes: u64,
+    /// Failed runs since the last passing one
+    pub consecutive_failures: u64,
+    pub latency: LatencyHistogram,
+    /// Most recent results as they ran, skips included
+    pub history: History,
+    /// When the check entered its current state, in milliseconds since the
+    /// Unix epoch; `None` while pending
+    pub changed_ms: Option<u64>,
+    /// When the latest result was recorded, skips included
+    pub last_run: Option<Instant>,
+    pub hysteresis: Hysteresis,
+    /// Reported state of the last completed run
+    pub passing: Option<bool>,
+}
+
+impl CheckState {
+    /// State keeping the last `history` results
+    pub fn with_history(history: usize) -> Self {
+        Self {
+            history: History::with_capacity(history),
+            ..Self::default()
+        }
+    }
+
+    pub fn record(&mut self, mut result: CheckResult) {
+        let entry = HistoryEntry::now(&result);
+        if result.skipped.is_none() {
+            self.runs += 1;
+            if result.ok {
+                self.consecutive_successes += 1;
+                self.consecutive_failures = 0;
+            } else {
+                self.failures += 1;
+                self.consecutive_failures += 1;
+                self.consecutive_successes = 0;
+            }
+            self.latency.observe(result.latency_ms);
+            self.settle(&mut result);
+        }
+        result.streak = Some(Streak {
+            successes: self.consecutive_successes,
+            failures: self.consecutive_failures,
+            rise: self.hysteresis.rise,
+            fall: self.hysteresis.fall,
+        });
+
+        if state_name(self.latest.as_ref()) != state_name(Some(&result)) {
+            self.changed_ms = Some(entry.timestamp_ms);
+        }
+        self.history.push(entry);
+        self.latest = Some(result);
+        self.last_run = Some(Instant::now());
+    }
+
+    /// Keep reporting the previous state until `fall` failures or `rise`
+    /// successes in a row confirm the change
+    fn settle(&mut self, result: &mut CheckResult) {
+        let Hysteresis { rise, fall } = self.hysteresis;
+        match self.passing {
+            // The error stays visible on the still passing result
+            Some(true) if !result.ok && self.consecutive_failures < u64::from(fall) => {
+                result.ok = true;
+            }
+            Some(false) if result.ok && self.consecutive_successes < u64::from(rise) => {
+                result.ok = false;
+                result.error = Some(format!(
+                    "recovering: {} of rise={rise} passing runs",
+                    self.consecutive_successes
+                ));
+            }
+            _ => {}
+        }
+        self.passing = Some(result.ok);
+    }
+}
+
+/// Cumulative latency histogram over `LATENCY_BUCKETS`
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    /// Observations at or below each bucket bound
+    pub buckets: [u64; LATENCY_BUCKETS.len()],
+    pub count: u64,
+    pub sum_ms: u64,
+}
+
+impl LatencyHistogram {
+    pub fn observe(&mut self, latency_ms: u64) {
+        let seconds = latency_ms as f64 / 1000.0;
+        for (bound, bucket) in LATENCY_BUCKETS.iter().zip(self.buckets.iter_mut()) {
+            if seconds <= *bound {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum_ms += latency_ms;
+    }
+}
@@ -0,0 +1,126 @@
+use super::*;
+use crate::runner::test_support::check_result;
+use crate::status::CheckResult;
+
+fn result(ok: bool, latency_ms: u64, skipped: Option<&str>) -> CheckResult {
+    CheckResult {
+        latency_ms,
+        skipped: skipped.map(str::to_string),
+        ..check_result(ok)
+    }
+}
+
+#[test]
+fn test_latency_histogram_is_cumulative() {
+    let mut state = CheckState::default();
+    state.record(result(true, 3, None));
+    state.record(result(false, 40, None));
+    state.record(result(true, 20_000, None));
+
+    let latency = &state.latency;
+    assert_eq!(latency.count, 3);
+    assert_eq!(latency.sum_ms, 20_043);
+    // le=0.005 holds 3ms, le=0.05 holds 3ms and 40ms, nothing fits 20s
+    assert_eq!(latency.buckets[0], 1);
+    assert_eq!(latency.buckets[3], 2);
+    assert_eq!(latency.buckets[LATENCY_BUCKETS.len() - 1], 2);
+}
+
+#[test]
+fn test_skipped_runs_are_not_counted() {
+    let mut state = CheckState::default();
+    state.record(result(false, 5, None));
+    state.record(result(false, 0, Some("dependency db failed")));
+
+    assert_eq!(state.runs, 1);
+    assert_eq!(state.failures, 1);
+    assert_eq!(state.consecutive_failures, 1);
+    assert_eq!(state.latency.count, 1);
+    assert!(state.latest.is_some_and(|r| r.skipped.is_some()));
+}
+
+#[test]
+fn test_state_tracks_changes_and_failure_streaks() {
+    let mut state = CheckState::default();
+    assert_eq!(state.changed_ms, None);
+
+    state.record(result(true, 1, None));
+    state.record(result(true, 2, None));
+    let first = state.history.entries().next().unwrap().timestamp_ms;
+    assert_eq!(state.changed_ms, Some(first));
+
+    state.record(result(false, 3, None));
+    state.record(result(false, 4, None));
+    let failed = state.history.entries().nth(2).unwrap().timestamp_ms;
+    assert_eq!(state.changed_ms, Some(failed));
+    assert_eq!(state.consecutive_failures, 2);
+
+    state.record(result(true, 5, None));
+    assert_eq!(state.consecutive_failures, 0);
+}
+
+#[test]
+fn test_jitter_stays_within_bounds() {
+    let mut random = jitter::Jitter::seeded(7);
+    let max = std::time::Duration::from_millis(25);
+    for _ in 0..1000 {
+        assert!(random.up_to(max) <= max);
+    }
+    assert!(random.up_to(std::time::Duration::ZERO).is_zero());
+    let huge = std::time::Duration::from_millis(u64::MAX);
+    assert!(random.up_to(huge) <= huge);
+    random.up_to(std::time::Duration::MAX);
+}
+
+#[test]
+fn test_history_keeps_the_most_recent_results() {
+    let mut state = CheckState::with_history(2);
+    state.record(result(true, 1, None));
+    state.record(result(false, 2, None));
+    state.record(result(false, 0, Some("dependency db failed")));
+
+    let kept: Vec<_> = state.history.entries().map(|e| e.latency_ms).collect();
+    assert_eq!(kept, vec![2, 0]);
+    let last = state.history.entries().last().unwrap();
+    assert_eq!(last.skipped.as_deref(), Some("dependency db failed"));
+    assert!(last.timestamp_ms > 0);
+
+    let mut none = CheckState::with_history(0);
+    none.record(result(true, 1, None));
+    assert_eq!(none.history.len(), 0);
+}
+
+fn monitor_of(name: &str) -> Monitor {
+    let check = PlannedCheck {
+        name: name.to_string(),
+        check_type: "tcp".to_string(),
+        target: "localhost:1".to_string(),
+        line: 1,
+        options: Default::default(),
+        probe: Err("not run in this test".to_string()),
+        deps: Vec::new(),
+    };
+    let loaded = Loaded {
+        plan: vec![check],
+        groups: Vec::new(),
+    };
+    Monitor::new(loaded, DEFAULT_HISTORY)
+}
+
+#[test]
+fn test_record_publishes_state_changes_only() {
+    let monitor = monitor_of("db");
+    let events = monitor.events().subscribe();
+    let skipped = || Outcome::Skipped("dependency net failed".to_string());
+
+    monitor.record(0, skipped());
+    monitor.record(0, skipped());
+    monitor.record(0, crate::runner::abandoned(&monitor.plan()[0], 900));
+    monitor.record(0, crate::runner::abandoned(&monitor.plan()[0], 900));
+
+    let seen: Vec<_> = events.try_iter().map(|t| (t.old, t.new)).collect();
+    assert_eq!(
+        seen,
+        vec![(Some("pending"), "skipped"), (Some("skipped"), "failing")]
+    );
+}
@@ -1,9 +1,11 @@
+use super::test_support::Cleanup;
 use super::*;
 
 #[test]
 fn test_validate_output_path_directory_with_dots() {
     // Create a test directory with dots in the name
     let test_dir = "./test.config.dir";
+    let _cleanup = Cleanup(&[test_dir]);
     std::fs::create_dir_all(test_dir).ok();
 
     // Test that a directory with dots is handled correctly
@@ -13,15 +15,13 @@ fn test_validate_output_path_directory_with_dots() {
         "Should allow directory with dots: {:?}",
         result
     );
-
-    // Clean up
-    std::fs::remove_dir(test_dir).ok();
 }
 
 #[test]
 fn test_validate_output_path_file_in_dotted_directory() {
     // Create a test directory with dots
     let test_dir = "./test.backup.dir";
+    let _cleanup = Cleanup(&[test_dir]);
     std::fs::create_dir_all(test_dir).ok();
 
     // Test file path within dotted directory
@@ -32,9 +32,6 @@ fn test_validate_output_path_file_in_dotted_directory() {
         "Should allow file in dotted directory: {:?}",
         result
     );
-
-    // Clean up
-    std::fs::remove_dir(test_dir).ok();
 }
 
 #[test]
@@ -45,6 +42,7 @@ fn test_validate_output_path_symlink() {
     // Create a test directory and a symlink to it
     let test_dir = "./test_real_dir";
     let symlink_path = "./test_symlink_dir";
+    let _cleanup = Cleanup(&[symlink_path, test_dir]);
 
     std::fs::create_dir_all(test_dir).ok();
     symlink(test_dir, symlink_path).ok();
@@ -60,10 +58,6 @@ fn test_validate_output_path_symlink() {
             "Symlink should resolve to real directory"
         );
     }
-
-    // Clean up
-    std::fs::remove_file(symlink_path).ok();
-    std::fs::remove_dir(test_dir).ok();
 }
 
 #[test]
@@ -122,7 +116,8 @@ fn test_validate_output_path_boundary_false_positives() {
         "./systemd-config", // starts with /sys but not in /sys
     ];
 
-    for test_path in test_cases {
+    let _cleanup = Cleanup(&test_cases);
+    for test_path in &test_cases {
         std::fs::create_dir_all(test_path).ok();
         let result = validate_output_path(test_path);
         assert!(
@@ -131,7 +126,6 @@ fn test_validate_output_path_boundary_false_positives() {
             test_path,
             result
         );
-        std::fs::remove_dir(test_path).ok();
     }
 }
 
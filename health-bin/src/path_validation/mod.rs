@@ -160,3 +160,6 @@ mod tests;
 
 #[cfg(test)]
 mod edge_case_tests;
+
+#[cfg(test)]
+mod test_support;
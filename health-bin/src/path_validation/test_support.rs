@@ -0,0 +1,15 @@
+//! Filesystem fixtures shared by the path validation tests
+
+/// Removes the paths a test created when it ends, even if an assertion fails
+pub(super) struct Cleanup<'a>(pub(super) &'a [&'a str]);
+
+impl Drop for Cleanup<'_> {
+    fn drop(&mut self) {
+        for path in self.0 {
+            // Symlinks first: remove_file does not follow them
+            std::fs::remove_file(path)
+                .or_else(|_| std::fs::remove_dir(path))
+                .ok();
+        }
+    }
+}
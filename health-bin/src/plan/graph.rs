@@ -0,0 +1,100 @@
+//! `depends_on=` resolution and cycle detection
+
+use std::collections::HashMap;
+
+use super::PlannedCheck;
+
+/// Resolve every check's `depends_on` names to plan indices
+///
+/// Fails on unknown names, self-dependencies and cycles so a broken
+/// dependency graph is rejected before any check runs.
+pub fn resolve_dependencies(plan: &mut [PlannedCheck]) -> Result<(), String> {
+    let index: HashMap<String, usize> = plan
+        .iter()
+        .enumerate()
+        .map(|(idx, check)| (check.name.clone(), idx))
+        .collect();
+
+    for check in plan.iter_mut() {
+        let mut deps = Vec::with_capacity(check.options.depends_on.len());
+        for dep in &check.options.depends_on {
+            match index.get(dep) {
+                Some(_) if *dep == check.name => {
+                    return Err(format!(
+                        "line {}: check '{}' depends on itself",
+                        check.line, check.name
+                    ));
+                }
+                Some(&idx) => deps.push(idx),
+                None => {
+                    return Err(format!(
+                        "line {}: check '{}' depends on unknown check '{dep}'",
+                        check.line, check.name
+                    ));
+                }
+            }
+        }
+        check.deps = deps;
+    }
+
+    match find_cycle(plan) {
+        Some(cycle) => Err(format!("dependency cycle: {}", cycle.join(" -> "))),
+        None => Ok(()),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// Depth-first search for a back edge, returning the cycle's names
+fn find_cycle(plan: &[PlannedCheck]) -> Option<Vec<String>> {
+    let mut marks = vec![Mark::Unvisited; plan.len()];
+    let mut stack = Vec::new();
+
+    for start in 0..plan.len() {
+        if marks[start] == Mark::Unvisited
+            && let Some(cycle) = visit(plan, start, &mut marks, &mut stack)
+        {
+            return Some(cycle);
+        }
+    }
+    None
+}
+
+fn visit(
+    plan: &[PlannedCheck],
+    node: usize,
+    marks: &mut [Mark],
+    stack: &mut Vec<usize>,
+) -> Option<Vec<String>> {
+    marks[node] = Mark::InProgress;
+    stack.push(node);
+
+    for &dep in &plan[node].deps {
+        match marks[dep] {
+            Mark::InProgress => {
+                let from = stack.iter().position(|&n| n == dep).unwrap_or(0);
+                let mut cycle: Vec<String> = stack[from..]
+                    .iter()
+                    .map(|&n| plan[n].name.clone())
+                    .collect();
+                cycle.push(plan[dep].name.clone());
+                return Some(cycle);
+            }
+            Mark::Unvisited => {
+                if let Some(cycle) = visit(plan, dep, marks, stack) {
+                    return Some(cycle);
+                }
+            }
+            Mark::Done => {}
+        }
+    }
+
+    stack.pop();
+    marks[node] = Mark::Done;
+    None
+}
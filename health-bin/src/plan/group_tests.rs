@@ -0,0 +1,39 @@
+use super::*;
+use crate::status::Severity;
+use healthcheck_core::config::parse_config_str;
+
+fn plan_for(config: &str) -> Vec<PlannedCheck> {
+    let configs = parse_config_str(config).expect("valid config");
+    build_plan(&configs, &build_registry()).expect("valid plan")
+}
+
+#[test]
+fn test_groups_collect_members_and_min_ok() {
+    let plan = plan_for(
+        "\
+tcp:name=a,host=localhost,port=1,group=api
+tcp:name=b,host=localhost,port=2,group=api,min_ok=2
+tcp:name=c,host=localhost,port=3,severity=warning,group=cache
+",
+    );
+    let groups = resolve_groups(&plan).expect("valid groups");
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].members, vec![0, 1]);
+    assert_eq!(groups[0].min_ok, 2);
+    assert_eq!(groups[0].severity, Severity::Critical);
+    assert_eq!(groups[1].min_ok, 1);
+    assert_eq!(groups[1].severity, Severity::Warning);
+}
+
+#[test]
+fn test_invalid_groups_are_rejected() {
+    for config in [
+        "tcp:name=a,host=localhost,port=1,min_ok=1",
+        "tcp:name=a,host=localhost,port=1,group=api,min_ok=2",
+        "tcp:name=a,host=localhost,port=1,group=a",
+        "tcp:name=a,host=localhost,port=1,group=g,min_ok=1\ntcp:name=b,host=localhost,port=2,group=g,min_ok=2",
+    ] {
+        let plan = plan_for(config);
+        assert!(resolve_groups(&plan).is_err(), "accepted: {config}");
+    }
+}
@@ -0,0 +1,93 @@
+//! Quorum groups from `group=` and `min_ok=` params
+
+use super::PlannedCheck;
+use crate::status::Severity;
+
+/// Checks reported together that pass when at least `min_ok` members pass
+#[derive(Debug)]
+pub struct PlannedGroup {
+    pub name: String,
+    pub min_ok: usize,
+    /// Plan indices of the member checks, in config order
+    pub members: Vec<usize>,
+    /// Critical unless every member is a warning check
+    pub severity: Severity,
+}
+
+/// Collect groups in order of their first member
+///
+/// `min_ok` defaults to 1 and may be repeated on several members as long
+/// as every occurrence agrees.
+pub fn resolve_groups(plan: &[PlannedCheck]) -> Result<Vec<PlannedGroup>, String> {
+    let mut groups: Vec<(PlannedGroup, Option<usize>)> = Vec::new();
+
+    for (idx, check) in plan.iter().enumerate() {
+        let Some(name) = &check.options.group else {
+            if check.options.min_ok.is_some() {
+                return Err(format!(
+                    "line {}: check '{}' sets min_ok without group",
+                    check.line, check.name
+                ));
+            }
+            continue;
+        };
+
+        if plan.iter().any(|c| &c.name == name) {
+            return Err(format!(
+                "line {}: group '{name}' has the same name as a check",
+                check.line
+            ));
+        }
+
+        let pos = match groups.iter().position(|(g, _)| &g.name == name) {
+            Some(pos) => pos,
+            None => {
+                groups.push((
+                    PlannedGroup {
+                        name: name.clone(),
+                        min_ok: 1,
+                        members: Vec::new(),
+                        severity: Severity::Warning,
+                    },
+                    None,
+                ));
+                groups.len() - 1
+            }
+        };
+        let (group, min_ok) = &mut groups[pos];
+
+        if let Some(value) = check.options.min_ok {
+            match min_ok {
+                Some(existing) if *existing != value => {
+                    return Err(format!(
+                        "line {}: group '{name}' has conflicting min_ok values {existing} and {value}",
+                        check.line
+                    ));
+                }
+                _ => *min_ok = Some(value),
+            }
+        }
+
+        group.members.push(idx);
+        if check.options.severity == Severity::Critical {
+            group.severity = Severity::Critical;
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(mut group, min_ok)| {
+            group.min_ok = min_ok.unwrap_or(1);
+            if group.min_ok > group.members.len() {
+                return Err(format!(
+                    "line {}: group '{}' requires min_ok={} but has only {} member(s)",
+                    plan[group.members[0]].line,
+                    group.name,
+                    group.min_ok,
+                    group.members.len()
+                ));
+            }
+            Ok(group)
+        })
+        .collect()
+}
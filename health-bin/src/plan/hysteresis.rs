@@ -0,0 +1,39 @@
+//! `rise=` and `fall=`: consecutive runs needed to change a check's state
+//!
+//! As in HAProxy, a passing check is only reported failing after `fall`
+//! failed runs in a row and a failing check only passing again after `rise`
+//! passing runs in a row, so a single blip does not flap the state. Both
+//! default to 1, where every run counts. One-shot runs ignore them.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hysteresis {
+    pub rise: u32,
+    pub fall: u32,
+}
+
+impl Default for Hysteresis {
+    fn default() -> Self {
+        Self { rise: 1, fall: 1 }
+    }
+}
+
+impl Hysteresis {
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        Ok(Self {
+            rise: count_param(params, "rise")?.unwrap_or(1),
+            fall: count_param(params, "fall")?.unwrap_or(1),
+        })
+    }
+}
+
+fn count_param(params: &HashMap<String, String>, key: &str) -> Result<Option<u32>, String> {
+    params
+        .get(key)
+        .map(|value| match value.parse::<u32>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("{key} must be a positive integer, got '{value}'")),
+        })
+        .transpose()
+}
@@ -0,0 +1,90 @@
+//! Latency thresholds from `warn_latency_ms=` and `max_latency_ms=`
+//!
+//! Both are checked after the probe returns: a success slower than
+//! `max_latency_ms` becomes a failure (and is retried like any other), a
+//! success slower than `warn_latency_ms` stays ok but carries a warning.
+
+use std::collections::HashMap;
+
+use healthcheck_core::{probes::ProbeResult, registry::HealthCheck};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LatencyThresholds {
+    pub warn_ms: Option<u64>,
+    pub max_ms: Option<u64>,
+}
+
+impl LatencyThresholds {
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        let thresholds = Self {
+            warn_ms: threshold_param(params, "warn_latency_ms")?,
+            max_ms: threshold_param(params, "max_latency_ms")?,
+        };
+
+        if let (Some(warn), Some(max)) = (thresholds.warn_ms, thresholds.max_ms)
+            && warn >= max
+        {
+            return Err(format!(
+                "warn_latency_ms ({warn}) must be below max_latency_ms ({max})"
+            ));
+        }
+        Ok(thresholds)
+    }
+
+    /// Turn a successful but too slow result into a failure
+    pub fn enforce_max(&self, result: ProbeResult) -> ProbeResult {
+        match self.max_ms {
+            Some(max) if result.ok && result.latency_ms > max => ProbeResult::failure(
+                result.latency_ms,
+                format!(
+                    "latency {}ms exceeded max_latency_ms={max}",
+                    result.latency_ms
+                ),
+            ),
+            _ => result,
+        }
+    }
+
+    /// Warning for a successful result slower than `warn_latency_ms`
+    pub fn warning(&self, result: &ProbeResult) -> Option<String> {
+        let warn = self.warn_ms?;
+        (result.ok && result.latency_ms > warn).then(|| {
+            format!(
+                "latency {}ms exceeded warn_latency_ms={warn}",
+                result.latency_ms
+            )
+        })
+    }
+}
+
+/// A probe whose every attempt is held to `max_latency_ms`
+pub struct Bounded<'a> {
+    pub check: &'a dyn HealthCheck,
+    pub thresholds: LatencyThresholds,
+}
+
+impl HealthCheck for Bounded<'_> {
+    fn check(&self) -> ProbeResult {
+        self.thresholds.enforce_max(self.check.check())
+    }
+
+    fn name(&self) -> &str {
+        self.check.name()
+    }
+
+    fn target(&self) -> &str {
+        self.check.target()
+    }
+}
+
+fn threshold_param(params: &HashMap<String, String>, key: &str) -> Result<Option<u64>, String> {
+    params
+        .get(key)
+        .map(|value| match value.parse::<u64>() {
+            Ok(ms) if ms > 0 => Ok(ms),
+            _ => Err(format!(
+                "{key} must be a positive number of milliseconds, got '{value}'"
+            )),
+        })
+        .transpose()
+}
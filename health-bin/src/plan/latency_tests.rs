@@ -0,0 +1,49 @@
+use super::latency::LatencyThresholds;
+use healthcheck_core::probes::ProbeResult;
+use std::collections::HashMap;
+
+fn thresholds(pairs: &[(&str, &str)]) -> Result<LatencyThresholds, String> {
+    let params: HashMap<String, String> = pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    LatencyThresholds::from_params(&params)
+}
+
+#[test]
+fn test_latency_thresholds_validation() {
+    assert!(thresholds(&[]).is_ok());
+    assert!(thresholds(&[("warn_latency_ms", "100"), ("max_latency_ms", "500")]).is_ok());
+    assert!(thresholds(&[("warn_latency_ms", "500"), ("max_latency_ms", "500")]).is_err());
+    assert!(thresholds(&[("max_latency_ms", "0")]).is_err());
+    assert!(thresholds(&[("warn_latency_ms", "fast")]).is_err());
+}
+
+#[test]
+fn test_max_latency_fails_slow_success() {
+    let limits = thresholds(&[("max_latency_ms", "100")]).unwrap();
+
+    let slow = limits.enforce_max(ProbeResult::success(150));
+    assert!(!slow.ok);
+    assert_eq!(
+        slow.error.as_deref(),
+        Some("latency 150ms exceeded max_latency_ms=100")
+    );
+    assert!(limits.enforce_max(ProbeResult::success(100)).ok);
+
+    // Failures keep their own error
+    let failed = limits.enforce_max(ProbeResult::failure(150, "refused"));
+    assert_eq!(failed.error.as_deref(), Some("refused"));
+}
+
+#[test]
+fn test_warn_latency_only_flags_slow_success() {
+    let limits = thresholds(&[("warn_latency_ms", "100")]).unwrap();
+
+    assert_eq!(
+        limits.warning(&ProbeResult::success(120)).as_deref(),
+        Some("latency 120ms exceeded warn_latency_ms=100")
+    );
+    assert_eq!(limits.warning(&ProbeResult::success(80)), None);
+    assert_eq!(limits.warning(&ProbeResult::failure(120, "refused")), None);
+}
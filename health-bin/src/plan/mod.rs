@@ -0,0 +1,135 @@
+//! Execution plan built from parsed config lines
+//!
+//! Resolves every config line to a uniquely named check instance together
+//! with the generic execution policy that applies to all probe types.
+//! Lines whose check cannot be created stay in the plan so the failure is
+//! reported alongside the other results.
+
+mod graph;
+mod groups;
+mod hysteresis;
+mod latency;
+mod naming;
+mod options;
+mod probe_class;
+mod run;
+mod schedule;
+mod select;
+
+pub use groups::{PlannedGroup, resolve_groups};
+pub use hysteresis::Hysteresis;
+pub use options::CheckOptions;
+pub use probe_class::ProbeClass;
+pub use select::Selection;
+
+use healthcheck_core::{
+    config::CheckConfig,
+    probes::{database::DatabaseCheck, http::HttpCheck, process::ProcessCheck, tcp::TcpCheck},
+    registry::{CheckRegistry, HealthCheck},
+};
+use log::error;
+
+/// A config line resolved to a runnable check
+pub struct PlannedCheck {
+    pub name: String,
+    pub check_type: String,
+    pub target: String,
+    /// Config line the check was defined on
+    pub line: usize,
+    pub options: CheckOptions,
+    pub probe: Result<Box<dyn HealthCheck>, String>,
+    /// Plan indices of the checks this one depends on
+    pub deps: Vec<usize>,
+}
+
+pub fn build_registry() -> CheckRegistry {
+    let mut registry = CheckRegistry::new();
+
+    registry.register("tcp", TcpCheck::from_params);
+    registry.register("http", HttpCheck::from_params);
+    registry.register("database", DatabaseCheck::from_params);
+    registry.register("process", ProcessCheck::from_params);
+
+    registry
+}
+
+/// Instantiate every configured check through the registry
+///
+/// Fails only when the dependency graph is invalid; checks that cannot be
+/// created are kept and fail when run.
+pub fn build_plan(
+    configs: &[CheckConfig],
+    registry: &CheckRegistry,
+) -> Result<Vec<PlannedCheck>, String> {
+    let mut plan = instantiate(configs, registry);
+    graph::resolve_dependencies(&mut plan)?;
+    Ok(plan)
+}
+
+/// Every problem that would make the plan fail or a check fail to create,
+/// without running anything
+pub fn plan_problems(configs: &[CheckConfig], registry: &CheckRegistry) -> Vec<String> {
+    let mut plan = instantiate(configs, registry);
+    let mut problems: Vec<String> = plan
+        .iter()
+        .filter_map(|check| {
+            let e = check.probe.as_ref().err()?;
+            Some(format!("line {}: {e}", check.line))
+        })
+        .collect();
+
+    problems.extend(graph::resolve_dependencies(&mut plan).err());
+    problems.extend(resolve_groups(&plan).err());
+    problems
+}
+
+fn instantiate(configs: &[CheckConfig], registry: &CheckRegistry) -> Vec<PlannedCheck> {
+    let mut names = naming::NameAllocator::new(configs);
+
+    configs
+        .iter()
+        .map(|config| {
+            let (options, probe) = match CheckOptions::from_params(&config.params) {
+                Ok(options) => (
+                    options,
+                    registry.create_check(&config.check_type, &config.params),
+                ),
+                Err(e) => (CheckOptions::default(), Err(e)),
+            };
+
+            if let Err(e) = &probe {
+                error!("Failed to create check '{}': {e}", config.check_type);
+            }
+
+            let target = probe
+                .as_ref()
+                .map(|check| check.target().to_string())
+                .unwrap_or_default();
+
+            PlannedCheck {
+                name: names.resolve(config, &target),
+                check_type: config.check_type.clone(),
+                target,
+                line: config.line,
+                options,
+                probe,
+                deps: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod group_tests;
+
+#[cfg(test)]
+mod latency_tests;
+
+#[cfg(test)]
+mod select_tests;
+
+#[cfg(test)]
+mod options_tests;
@@ -0,0 +1,50 @@
+//! Check name resolution
+//!
+//! Explicit `name=` labels are unique (the config parser enforces it) and
+//! reserved up front. Unlabelled checks default to `type:target`, with a
+//! `-N` suffix when several lines probe the same target.
+
+use std::collections::HashSet;
+
+use healthcheck_core::config::CheckConfig;
+
+pub struct NameAllocator {
+    taken: HashSet<String>,
+}
+
+impl NameAllocator {
+    pub fn new(configs: &[CheckConfig]) -> Self {
+        Self {
+            taken: configs
+                .iter()
+                .filter_map(|c| c.label().map(str::to_string))
+                .collect(),
+        }
+    }
+
+    /// Final name for a config line whose check probes `target`
+    pub fn resolve(&mut self, config: &CheckConfig, target: &str) -> String {
+        if let Some(label) = config.label() {
+            return label.to_string();
+        }
+
+        let base = default_name(&config.check_type, target, config.line);
+        let mut candidate = base.clone();
+        let mut n = 2;
+        while !self.taken.insert(candidate.clone()) {
+            candidate = format!("{base}-{n}");
+            n += 1;
+        }
+        candidate
+    }
+}
+
+/// `type:target` with any URL scheme stripped, e.g. `http:localhost:8080/health`
+fn default_name(check_type: &str, target: &str, line: usize) -> String {
+    if target.is_empty() {
+        return format!("{check_type}:line{line}");
+    }
+
+    let target = target.split_once("://").map_or(target, |(_, rest)| rest);
+    format!("{check_type}:{target}")
+}
@@ -0,0 +1,78 @@
+//! Probe-independent params accepted on every config line
+
+use std::collections::HashMap;
+
+use healthcheck_core::config::validate_check_name;
+
+use super::hysteresis::Hysteresis;
+use super::latency::LatencyThresholds;
+use super::probe_class::ProbeClasses;
+use super::schedule::Schedule;
+use crate::runner::RetryPolicy;
+use crate::status::Severity;
+
+/// Execution and reporting policy shared by all probe types
+#[derive(Debug, Clone, Default)]
+pub struct CheckOptions {
+    pub retry: RetryPolicy,
+    pub severity: Severity,
+    pub latency: LatencyThresholds,
+    /// Names of checks that must pass before this one runs
+    pub depends_on: Vec<String>,
+    /// Labels for `--tag` selection
+    pub tags: Vec<String>,
+    /// Quorum group this check reports under
+    pub group: Option<String>,
+    /// Members of `group` that must pass for the group to pass
+    pub min_ok: Option<usize>,
+    /// Serve-mode endpoints (`/livez`, `/readyz`, `/startupz`) this check
+    /// counts for
+    pub probes: ProbeClasses,
+    pub schedule: Schedule,
+    /// Consecutive runs needed to change state in long-running modes
+    pub hysteresis: Hysteresis,
+}
+
+impl CheckOptions {
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        Ok(Self {
+            retry: RetryPolicy::from_params(params)?,
+            severity: Severity::from_params(params)?,
+            latency: LatencyThresholds::from_params(params)?,
+            depends_on: list_param(params, "depends_on"),
+            tags: list_param(params, "tags"),
+            group: params.get("group").map(|g| parse_group(g)).transpose()?,
+            min_ok: params.get("min_ok").map(|v| parse_min_ok(v)).transpose()?,
+            probes: ProbeClasses::from_params(params)?,
+            schedule: Schedule::from_params(params)?,
+            hysteresis: Hysteresis::from_params(params)?,
+        })
+    }
+}
+
+fn parse_group(value: &str) -> Result<String, String> {
+    validate_check_name(value).map_err(|e| format!("invalid group: {e}"))?;
+    Ok(value.to_string())
+}
+
+fn parse_min_ok(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("min_ok must be a positive integer, got '{value}'")),
+    }
+}
+
+/// Split a `|`-separated list param, e.g. `depends_on=db|cache`
+fn list_param(params: &HashMap<String, String>, key: &str) -> Vec<String> {
+    params
+        .get(key)
+        .map(|value| {
+            value
+                .split('|')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,85 @@
+use super::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+fn options(pairs: &[(&str, &str)]) -> Result<CheckOptions, String> {
+    let params: HashMap<String, String> = pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    CheckOptions::from_params(&params)
+}
+
+#[test]
+fn test_probe_classes_default_to_readiness_and_startup() {
+    let probes = options(&[]).unwrap().probes;
+    assert!(!probes.contains(ProbeClass::Liveness));
+    assert!(probes.contains(ProbeClass::Readiness));
+    assert!(probes.contains(ProbeClass::Startup));
+
+    // Checks that fail to parse keep the same default
+    assert_eq!(CheckOptions::default().probes, probes);
+}
+
+#[test]
+fn test_probe_param_selects_classes() {
+    let probes = options(&[("probe", "liveness")]).unwrap().probes;
+    assert!(probes.contains(ProbeClass::Liveness));
+    assert!(!probes.contains(ProbeClass::Readiness));
+
+    let probes = options(&[("probe", "liveness|startup")]).unwrap().probes;
+    assert!(probes.contains(ProbeClass::Liveness));
+    assert!(probes.contains(ProbeClass::Startup));
+    assert!(!probes.contains(ProbeClass::Readiness));
+
+    assert!(options(&[("probe", "ready")]).is_err());
+}
+
+#[test]
+fn test_schedule_params() {
+    let schedule = options(&[("interval_ms", "5000"), ("initial_delay_ms", "250")])
+        .unwrap()
+        .schedule;
+    let interval = schedule.interval_or(Duration::from_secs(10));
+    assert_eq!(interval, Duration::from_secs(5));
+    assert_eq!(schedule.initial_delay, Duration::from_millis(250));
+    // Jitter defaults to a tenth of the interval
+    assert_eq!(schedule.jitter_for(interval), Duration::from_millis(500));
+
+    let schedule = options(&[("jitter_ms", "0")]).unwrap().schedule;
+    assert_eq!(
+        schedule.interval_or(Duration::from_secs(10)),
+        Duration::from_secs(10)
+    );
+    assert_eq!(schedule.jitter_for(Duration::from_secs(10)), Duration::ZERO);
+
+    assert!(options(&[("interval_ms", "0")]).is_err());
+    assert!(options(&[("jitter_ms", "soon")]).is_err());
+    assert!(options(&[("interval_ms", "86400000")]).is_ok());
+    for key in ["interval_ms", "jitter_ms", "initial_delay_ms"] {
+        assert!(options(&[(key, "86400001")]).is_err(), "{key}");
+        assert!(options(&[(key, "18446744073709551615")]).is_err(), "{key}");
+    }
+}
+
+#[test]
+fn test_rise_and_fall_params() {
+    assert_eq!(
+        options(&[]).unwrap().hysteresis,
+        Hysteresis { rise: 1, fall: 1 }
+    );
+
+    let hysteresis = options(&[("rise", "2"), ("fall", "3")]).unwrap().hysteresis;
+    assert_eq!(hysteresis, Hysteresis { rise: 2, fall: 3 });
+
+    for invalid in ["0", "-1", "two", ""] {
+        assert!(
+            options(&[("rise", invalid)]).is_err(),
+            "accepted rise={invalid}"
+        );
+        assert!(
+            options(&[("fall", invalid)]).is_err(),
+            "accepted fall={invalid}"
+        );
+    }
+}
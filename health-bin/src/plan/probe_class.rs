@@ -0,0 +1,66 @@
+//! Kubernetes probe classes from `probe=liveness|readiness|startup`
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeClass {
+    Liveness,
+    Readiness,
+    Startup,
+}
+
+/// Probe classes a check counts for
+///
+/// Checks without `probe=` count for readiness and startup but not
+/// liveness, so a failing dependency never gets the process restarted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbeClasses {
+    liveness: bool,
+    readiness: bool,
+    startup: bool,
+}
+
+impl ProbeClasses {
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        let Some(value) = params.get("probe") else {
+            return Ok(Self::default());
+        };
+
+        let mut classes = Self {
+            liveness: false,
+            readiness: false,
+            startup: false,
+        };
+        for class in value.split('|').map(str::trim) {
+            match class {
+                "liveness" => classes.liveness = true,
+                "readiness" => classes.readiness = true,
+                "startup" => classes.startup = true,
+                other => {
+                    return Err(format!(
+                        "probe must be liveness, readiness or startup, got '{other}'"
+                    ));
+                }
+            }
+        }
+        Ok(classes)
+    }
+
+    pub fn contains(&self, class: ProbeClass) -> bool {
+        match class {
+            ProbeClass::Liveness => self.liveness,
+            ProbeClass::Readiness => self.readiness,
+            ProbeClass::Startup => self.startup,
+        }
+    }
+}
+
+impl Default for ProbeClasses {
+    fn default() -> Self {
+        Self {
+            liveness: false,
+            readiness: true,
+            startup: true,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Running a planned check, with panics turned into failed runs
+
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use healthcheck_core::probes::{ProbeResult, elapsed_ms};
+use log::error;
+
+use super::{PlannedCheck, latency};
+use crate::runner::Attempted;
+
+impl PlannedCheck {
+    /// Execute the check under its retry policy and latency limit
+    ///
+    /// A probe that panics counts as a failed run, so every caller still
+    /// gets a result for the check.
+    pub fn run(&self) -> Attempted {
+        let start = Instant::now();
+        panic::catch_unwind(AssertUnwindSafe(|| self.run_probe())).unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            error!("Check '{}' panicked: {message}", self.name);
+            let error = format!("check panicked: {message}");
+            Attempted {
+                result: ProbeResult::failure(elapsed_ms(start), error.clone()),
+                attempts: 1,
+                attempt_errors: vec![error],
+            }
+        })
+    }
+
+    fn run_probe(&self) -> Attempted {
+        match &self.probe {
+            Ok(check) => self.options.retry.run(
+                &self.name,
+                &latency::Bounded {
+                    check: check.as_ref(),
+                    thresholds: self.options.latency,
+                },
+            ),
+            Err(e) => Attempted {
+                result: ProbeResult::failure(0, format!("check creation failed: {e}")),
+                attempts: 0,
+                attempt_errors: Vec::new(),
+            },
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Per-check timing for long-running modes
+//!
+//! `interval_ms=` overrides the `--interval` default, `jitter_ms=` adds a
+//! random delay of up to that much before every run (default: a tenth of
+//! the interval) and `initial_delay_ms=` postpones the first run. Each is
+//! at most a day. One-shot runs ignore all three.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Upper bound for every schedule param: one day
+const MAX_SCHEDULE_MS: u64 = 86_400_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Schedule {
+    pub interval: Option<Duration>,
+    pub jitter: Option<Duration>,
+    pub initial_delay: Duration,
+}
+
+impl Schedule {
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        let interval = millis_param(params, "interval_ms")?;
+        if interval.is_some_and(|i| i.is_zero()) {
+            return Err("interval_ms must be greater than zero".to_string());
+        }
+
+        Ok(Self {
+            interval,
+            jitter: millis_param(params, "jitter_ms")?,
+            initial_delay: millis_param(params, "initial_delay_ms")?.unwrap_or_default(),
+        })
+    }
+
+    pub fn interval_or(&self, default: Duration) -> Duration {
+        self.interval.unwrap_or(default)
+    }
+
+    /// Maximum random delay added before each run
+    pub fn jitter_for(&self, interval: Duration) -> Duration {
+        self.jitter.unwrap_or(interval / 10)
+    }
+}
+
+fn millis_param(params: &HashMap<String, String>, key: &str) -> Result<Option<Duration>, String> {
+    params
+        .get(key)
+        .map(|value| match value.parse::<u64>() {
+            Ok(ms) if ms <= MAX_SCHEDULE_MS => Ok(Duration::from_millis(ms)),
+            _ => Err(format!(
+                "{key} must be 0-{MAX_SCHEDULE_MS} milliseconds, got '{value}'"
+            )),
+        })
+        .transpose()
+}
@@ -0,0 +1,119 @@
+//! Running a subset of the configured checks (`--tag`, `--only`, `--skip`)
+
+use std::collections::HashMap;
+
+use log::info;
+
+use super::{PlannedCheck, PlannedGroup};
+use crate::status::Severity;
+
+/// Which checks of a plan to run
+///
+/// `--only` and `--skip` match a check's name or its type. A check must
+/// carry one of the `--tag` tags, match one `--only` entry and match no
+/// `--skip` entry; empty lists do not filter.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Selection {
+    pub tags: Vec<String>,
+    pub only: Vec<String>,
+    pub skip: Vec<String>,
+}
+
+impl Selection {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.only.is_empty() && self.skip.is_empty()
+    }
+
+    pub fn matches(&self, check: &PlannedCheck) -> bool {
+        let named = |list: &[String]| {
+            list.iter()
+                .any(|item| *item == check.name || *item == check.check_type)
+        };
+
+        (self.tags.is_empty() || self.tags.iter().any(|t| check.options.tags.contains(t)))
+            && (self.only.is_empty() || named(&self.only))
+            && !named(&self.skip)
+    }
+
+    /// Keep only the selected checks, remapping dependency indices
+    ///
+    /// Dependencies outside the selection are not run and no longer gate
+    /// the checks that depend on them.
+    pub fn apply(&self, plan: Vec<PlannedCheck>) -> Vec<PlannedCheck> {
+        if self.is_empty() {
+            return plan;
+        }
+
+        let keep: Vec<bool> = plan.iter().map(|check| self.matches(check)).collect();
+        let remap = self.remap(&plan);
+        let names: Vec<String> = plan.iter().map(|check| check.name.clone()).collect();
+        plan.into_iter()
+            .zip(keep)
+            .filter(|(_, kept)| *kept)
+            .map(|(mut check, _)| {
+                check.deps = std::mem::take(&mut check.deps)
+                    .into_iter()
+                    .filter_map(|dep| {
+                        let mapped = remap.get(&dep).copied();
+                        if mapped.is_none() {
+                            info!(
+                                "Check '{}': dependency {} is not selected",
+                                check.name, names[dep]
+                            );
+                        }
+                        mapped
+                    })
+                    .collect();
+                check
+            })
+            .collect()
+    }
+
+    /// Narrow groups resolved on the full plan to their selected members
+    ///
+    /// `min_ok` is clamped to the members left and the severity follows
+    /// them; a group without any selected member is dropped.
+    pub fn apply_groups(
+        &self,
+        plan: &[PlannedCheck],
+        groups: Vec<PlannedGroup>,
+    ) -> Vec<PlannedGroup> {
+        if self.is_empty() {
+            return groups;
+        }
+
+        let remap = self.remap(plan);
+        groups
+            .into_iter()
+            .filter_map(|mut group| {
+                group.members.retain(|member| remap.contains_key(member));
+                if group.members.is_empty() {
+                    return None;
+                }
+                if group.min_ok > group.members.len() {
+                    info!(
+                        "Group '{}': min_ok lowered to the {} selected member(s)",
+                        group.name,
+                        group.members.len()
+                    );
+                    group.min_ok = group.members.len();
+                }
+                let critical = |&m: &usize| plan[m].options.severity == Severity::Critical;
+                if !group.members.iter().any(critical) {
+                    group.severity = Severity::Warning;
+                }
+                group.members = group.members.iter().map(|m| remap[m]).collect();
+                Some(group)
+            })
+            .collect()
+    }
+
+    /// New index of every selected check, by its index in `plan`
+    fn remap(&self, plan: &[PlannedCheck]) -> HashMap<usize, usize> {
+        let mut remap = HashMap::new();
+        for (old, _) in plan.iter().enumerate().filter(|(_, c)| self.matches(c)) {
+            remap.insert(old, remap.len());
+        }
+        remap
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use healthcheck_core::config::parse_config_str;
+
+const CONFIG: &str = "\
+tcp:name=db,host=localhost,port=5432,tags=readiness
+http:name=api,url=http://localhost:8080,tags=readiness|liveness,depends_on=db
+process:name=nginx
+";
+
+fn selected(selection: Selection) -> Vec<String> {
+    let configs = parse_config_str(CONFIG).expect("valid config");
+    let plan = build_plan(&configs, &build_registry()).expect("valid plan");
+    selection
+        .apply(plan)
+        .into_iter()
+        .map(|check| check.name)
+        .collect()
+}
+
+fn list(items: &[&str]) -> Vec<String> {
+    items.iter().map(|i| i.to_string()).collect()
+}
+
+#[test]
+fn test_empty_selection_keeps_everything() {
+    assert_eq!(selected(Selection::default()).len(), 3);
+}
+
+#[test]
+fn test_select_by_tag() {
+    let selection = Selection {
+        tags: list(&["liveness"]),
+        ..Selection::default()
+    };
+    assert_eq!(selected(selection), vec!["api"]);
+}
+
+#[test]
+fn test_only_and_skip_match_name_or_type() {
+    let only = Selection {
+        only: list(&["db", "process"]),
+        ..Selection::default()
+    };
+    assert_eq!(selected(only), vec!["db", "process:nginx"]);
+
+    let skip = Selection {
+        tags: list(&["readiness"]),
+        skip: list(&["http"]),
+        ..Selection::default()
+    };
+    assert_eq!(selected(skip), vec!["db"]);
+}
+
+#[test]
+fn test_unselected_dependencies_are_dropped() {
+    let configs = parse_config_str(CONFIG).expect("valid config");
+    let plan = build_plan(&configs, &build_registry()).expect("valid plan");
+    assert_eq!(plan[1].deps, vec![0]);
+
+    let selection = Selection {
+        only: list(&["api"]),
+        ..Selection::default()
+    };
+    let plan = selection.apply(plan);
+    assert_eq!(plan.len(), 1);
+    assert!(plan[0].deps.is_empty());
+}
+
+#[test]
+fn test_selecting_one_member_of_a_quorum_group() {
+    let configs = parse_config_str(
+        "\
+tcp:name=a,host=localhost,port=1,group=api,min_ok=2
+tcp:name=b,host=localhost,port=2,group=api
+tcp:name=c,host=localhost,port=3,group=cache
+",
+    )
+    .expect("valid config");
+    let plan = build_plan(&configs, &build_registry()).expect("valid plan");
+    let groups = resolve_groups(&plan).expect("valid groups");
+    let selection = Selection {
+        only: list(&["b"]),
+        ..Selection::default()
+    };
+
+    let groups = selection.apply_groups(&plan, groups);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].name, "api");
+    assert_eq!(groups[0].members, vec![0]);
+    assert_eq!(groups[0].min_ok, 1);
+    assert_eq!(selection.apply(plan)[0].name, "b");
+}
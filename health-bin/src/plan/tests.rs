@@ -0,0 +1,128 @@
+use super::*;
+use healthcheck_core::config::parse_config_str;
+
+fn plan_for(config: &str) -> Vec<PlannedCheck> {
+    try_plan(config).expect("valid plan")
+}
+
+fn try_plan(config: &str) -> Result<Vec<PlannedCheck>, String> {
+    let configs = parse_config_str(config).expect("valid config");
+    build_plan(&configs, &build_registry())
+}
+
+fn names(plan: &[PlannedCheck]) -> Vec<&str> {
+    plan.iter().map(|c| c.name.as_str()).collect()
+}
+
+#[test]
+fn test_default_names_use_type_and_target() {
+    let plan = plan_for(
+        "\
+tcp:host=localhost,port=5432
+http:url=http://localhost:8080/health
+process:name=nginx
+",
+    );
+    assert_eq!(
+        names(&plan),
+        vec![
+            "tcp:localhost:5432",
+            "http:localhost:8080/health",
+            "process:nginx"
+        ]
+    );
+    assert_eq!(plan[1].target, "http://localhost:8080/health");
+}
+
+#[test]
+fn test_explicit_names_win() {
+    let plan = plan_for(
+        "\
+tcp:name=primary,host=db1,port=5432
+tcp:name=replica,host=db2,port=5432
+process:name=cron,process=crond
+",
+    );
+    assert_eq!(names(&plan), vec!["primary", "replica", "cron"]);
+    assert_eq!(plan[2].target, "crond");
+}
+
+#[test]
+fn test_duplicate_default_names_get_suffix() {
+    let plan = plan_for(
+        "\
+tcp:port=5432
+tcp:port=5432,timeout_ms=100
+tcp:name=tcp:localhost:5432-2,port=6432
+",
+    );
+    assert_eq!(
+        names(&plan),
+        vec![
+            "tcp:localhost:5432",
+            "tcp:localhost:5432-3",
+            "tcp:localhost:5432-2"
+        ]
+    );
+}
+
+#[test]
+fn test_creation_failure_keeps_line_based_name() {
+    let plan = plan_for("tcp:host=localhost\nbogus:x=1\n");
+    assert_eq!(names(&plan), vec!["tcp:line1", "bogus:line2"]);
+    assert!(plan.iter().all(|c| c.probe.is_err()));
+
+    let outcome = plan[1].run();
+    assert!(!outcome.result.ok);
+    assert!(outcome.result.error.unwrap().contains("unknown check type"));
+}
+
+#[test]
+fn test_invalid_retry_params_fail_creation() {
+    let plan = plan_for("tcp:port=22,retries=lots\n");
+    assert!(plan[0].probe.is_err());
+}
+
+#[test]
+fn test_depends_on_resolves_names() {
+    let plan = plan_for(
+        "\
+tcp:name=db,port=5432
+database:name=query,depends_on=db
+http:url=http://localhost/health,depends_on=db|query
+",
+    );
+    assert_eq!(plan[1].deps, vec![0]);
+    assert_eq!(plan[2].deps, vec![0, 1]);
+}
+
+#[test]
+fn test_depends_on_accepts_default_names() {
+    let plan = plan_for("tcp:port=5432\nprocess:name=postgres,depends_on=tcp:localhost:5432\n");
+    assert_eq!(plan[1].deps, vec![0]);
+}
+
+#[test]
+fn test_depends_on_rejects_unknown_check() {
+    let err = try_plan("tcp:name=a,port=1,depends_on=missing")
+        .err()
+        .unwrap();
+    assert!(err.contains("unknown check 'missing'"), "{err}");
+}
+
+#[test]
+fn test_depends_on_rejects_cycles() {
+    let err = try_plan(
+        "\
+tcp:name=a,port=1,depends_on=c
+tcp:name=b,port=2,depends_on=a
+tcp:name=c,port=3,depends_on=b
+",
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err, "dependency cycle: a -> c -> b -> a");
+
+    let err = try_plan("tcp:name=a,port=1,depends_on=a").err().unwrap();
+    assert!(err.contains("depends on itself"), "{err}");
+}
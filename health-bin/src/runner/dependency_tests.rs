@@ -0,0 +1,35 @@
+use super::*;
+use pool::Outcome;
+use std::time::{Duration, Instant};
+use test_support::{completed, sleep_check};
+
+#[test]
+fn test_run_parallel_skips_dependents_of_failed_checks() {
+    let mut checks = vec![
+        sleep_check(10, false),
+        sleep_check(10, true),
+        sleep_check(10, true),
+        sleep_check(10, true),
+    ];
+    checks[1].deps = vec![0];
+    checks[2].deps = vec![1];
+    checks[3].deps = vec![];
+
+    let results = pool::run_parallel(Arc::new(checks), 4, None);
+    assert!(!completed(&results[0]).result.ok);
+    assert!(matches!(&results[1], Outcome::Skipped(r) if r == "dependency sleep-10 failed"));
+    assert!(matches!(&results[2], Outcome::Skipped(r) if r.ends_with("skipped")));
+    assert!(completed(&results[3]).result.ok);
+}
+
+#[test]
+fn test_run_parallel_runs_dependents_after_dependencies() {
+    let mut checks = vec![sleep_check(100, true), sleep_check(10, true)];
+    checks[0].name = "slow".to_string();
+    checks[1].deps = vec![0];
+
+    let start = Instant::now();
+    let results = pool::run_parallel(Arc::new(checks), 2, None);
+    assert!(completed(&results[1]).result.ok);
+    assert!(start.elapsed() >= Duration::from_millis(110));
+}
@@ -0,0 +1,65 @@
+//! Config loading shared by one-shot runs and serve mode
+
+use healthcheck_core::config::{CheckConfig, SETTINGS_TYPE, parse_config_file, split_settings};
+use log::{error, info};
+
+use super::RunOptions;
+use crate::plan::{
+    PlannedCheck, PlannedGroup, Selection, build_plan, build_registry, resolve_groups,
+};
+
+/// The checks to run and the quorum groups they form
+pub struct Loaded {
+    pub plan: Vec<PlannedCheck>,
+    pub groups: Vec<PlannedGroup>,
+}
+
+/// Parse the config, merge its `settings:` into `options` and build the
+/// plan for the selected checks
+///
+/// Errors are already logged and worded for the JSON `"error"` field.
+pub fn load_checks(config_path: &str, options: &mut RunOptions) -> Result<Loaded, String> {
+    info!("Loading healthcheck config from: {config_path}");
+
+    let check_configs = parse_config_file(config_path).map_err(|e| {
+        error!("Failed to parse config: {e}");
+        format!("config parse error: {e}")
+    })?;
+
+    let invalid = |e: String| {
+        error!("Invalid settings: {e}");
+        format!("config parse error: {e}")
+    };
+    for settings in check_configs
+        .iter()
+        .filter(|c| c.check_type == SETTINGS_TYPE)
+    {
+        RunOptions::check_settings_line(settings).map_err(invalid)?;
+    }
+    let (settings, check_configs) = split_settings(check_configs);
+    options.merge_settings(&settings).map_err(invalid)?;
+
+    if check_configs.is_empty() {
+        error!("No checks configured");
+        return Err("no checks configured".to_string());
+    }
+
+    select_plan(&check_configs, &options.selection).map_err(|e| {
+        error!("Invalid check configuration: {e}");
+        format!("config parse error: {e}")
+    })
+}
+
+/// Build the plan and its groups, then narrow both to the selected checks
+///
+/// Groups are validated on the full plan, so selecting part of a quorum
+/// group does not make its `min_ok` invalid.
+fn select_plan(configs: &[CheckConfig], selection: &Selection) -> Result<Loaded, String> {
+    let plan = build_plan(configs, &build_registry())?;
+    let groups = selection.apply_groups(&plan, resolve_groups(&plan)?);
+    let plan = selection.apply(plan);
+    if plan.is_empty() {
+        return Err("no checks match the selection".to_string());
+    }
+    Ok(Loaded { plan, groups })
+}
@@ -0,0 +1,111 @@
+//! Health check execution engine
+//!
+//! This module orchestrates the execution of health checks:
+//! - Reads and parses configuration files
+//! - Registers all available check types (TCP, HTTP, database, process)
+//! - Executes checks in parallel on a bounded worker pool (`--concurrency`)
+//! - Abandons checks still running at the run deadline (`--deadline-ms`)
+//! - Retries failed checks per their `retries=` policy
+//! - Runs only the checks selected by `--tag`, `--only` and `--skip`
+//! - Skips checks whose `depends_on=` dependencies failed
+//! - Reports `group=` members as quorum groups
+//! - Collects results and outputs JSON status
+//! - Or reports a running `serve` instance's results (`--from unix:PATH`)
+//!
+//! ## Logging
+//!
+//! Uses `env_logger` with INFO level by default. Set `RUST_LOG` environment
+//! variable to control verbosity:
+//!
+//! ```sh
+//! RUST_LOG=debug healthcheck config.conf
+//! RUST_LOG=error healthcheck config.conf
+//! ```
+//!
+//! ## Error Handling
+//!
+//! Individual check failures are captured and reported in the JSON output.
+//! Each check has a severity: a failing `critical` check makes the run
+//! `unhealthy`, a failing `warning` check only `degraded`. The exit code for
+//! each state depends on the `--exit-codes` mapping (Docker or Nagios).
+
+use std::sync::Arc;
+
+use log::info;
+
+mod load;
+mod options;
+mod pool;
+mod remote;
+mod report;
+mod retry;
+
+pub use load::{Loaded, load_checks};
+pub use options::{RunOptions, parse_concurrency, parse_deadline_ms};
+pub use pool::{Outcome, abandoned, run_parallel};
+pub use report::{assemble_report, to_result};
+pub use retry::{Attempted, RetryPolicy};
+
+use crate::status::{OverallStatus, print_error_json, print_results};
+
+pub fn init_logger() {
+    // Use env_logger's built-in default filter instead of unsafe env::set_var
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .parse_default_env()
+        .format_timestamp_millis()
+        .try_init();
+}
+
+pub fn run_health_checks(config_path: &str, options: RunOptions) {
+    init_logger();
+
+    if let Some(socket) = &options.from {
+        remote::report_from_server(socket, &options);
+        return;
+    }
+
+    let mut options = options;
+    let Loaded { plan, groups } = match load_checks(config_path, &mut options) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            print_error_json(&e);
+            std::process::exit(options.exit_codes().config_error());
+        }
+    };
+    let plan = Arc::new(plan);
+
+    info!(
+        "Running {} health checks (concurrency {}, deadline {:?})",
+        plan.len(),
+        options.concurrency(),
+        options.deadline
+    );
+    let outcomes = run_parallel(Arc::clone(&plan), options.concurrency(), options.deadline);
+    let results = plan
+        .iter()
+        .zip(outcomes)
+        .map(|(check, outcome)| to_result(check, outcome))
+        .collect();
+    let entries = assemble_report(&plan, &groups, results);
+    let status = OverallStatus::from_entries(&entries);
+
+    print_results(&entries, status);
+
+    let code = options.exit_codes().for_status(status);
+    if code != 0 {
+        std::process::exit(code);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_support;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod dependency_tests;
+
+#[cfg(test)]
+mod retry_tests;
@@ -0,0 +1,105 @@
+//! Run-wide options from CLI flags and `settings:` config lines
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use healthcheck_core::config::CheckConfig;
+
+use crate::plan::Selection;
+use crate::server::TokenSource;
+use crate::status::ExitCodes;
+
+/// Default upper bound on checks executing at the same time
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Keys a `settings:` line may set
+const SETTINGS_KEYS: [&str; 3] = ["concurrency", "deadline_ms", "exit_codes"];
+
+/// Tunables for a single run of the configured checks
+///
+/// Fields left as `None` by the CLI are filled from the config file's
+/// `settings:` lines; CLI flags always take precedence.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub concurrency: Option<usize>,
+    pub deadline: Option<Duration>,
+    pub exit_codes: Option<ExitCodes>,
+    /// Subset of checks to run; CLI only
+    pub selection: Selection,
+    /// Report a running `serve` instance's results from this Unix socket
+    /// instead of probing; CLI only
+    pub from: Option<PathBuf>,
+    /// Bearer token sent with `from`; CLI only
+    pub from_token: Option<TokenSource>,
+}
+
+impl RunOptions {
+    /// Fill unset options from `settings:` params; unknown keys are errors
+    pub fn merge_settings(&mut self, settings: &HashMap<String, String>) -> Result<(), String> {
+        let mut unknown: Vec<&String> = settings
+            .keys()
+            .filter(|key| !SETTINGS_KEYS.contains(&key.as_str()))
+            .collect();
+        unknown.sort();
+        if let Some(key) = unknown.first() {
+            return Err(format!(
+                "unknown setting '{key}', expected one of: {}",
+                SETTINGS_KEYS.join(", ")
+            ));
+        }
+
+        if self.concurrency.is_none()
+            && let Some(value) = settings.get("concurrency")
+        {
+            self.concurrency = Some(parse_concurrency(value)?);
+        }
+
+        if self.deadline.is_none()
+            && let Some(value) = settings.get("deadline_ms")
+        {
+            self.deadline = Some(parse_deadline_ms(value)?);
+        }
+
+        if self.exit_codes.is_none()
+            && let Some(value) = settings.get("exit_codes")
+        {
+            self.exit_codes = Some(ExitCodes::parse(value)?);
+        }
+
+        Ok(())
+    }
+
+    /// Check one `settings:` line on its own, naming the line on failure
+    pub fn check_settings_line(settings: &CheckConfig) -> Result<(), String> {
+        Self::default()
+            .merge_settings(&settings.params)
+            .map_err(|e| format!("line {}: {e}", settings.line))
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency.unwrap_or(DEFAULT_CONCURRENCY)
+    }
+
+    pub fn exit_codes(&self) -> ExitCodes {
+        self.exit_codes.unwrap_or_default()
+    }
+}
+
+pub fn parse_concurrency(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!(
+            "concurrency must be a positive integer, got '{value}'"
+        )),
+    }
+}
+
+pub fn parse_deadline_ms(value: &str) -> Result<Duration, String> {
+    match value.parse::<u64>() {
+        Ok(ms) if ms > 0 => Ok(Duration::from_millis(ms)),
+        _ => Err(format!(
+            "deadline_ms must be a positive number of milliseconds, got '{value}'"
+        )),
+    }
+}
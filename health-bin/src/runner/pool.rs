@@ -0,0 +1,144 @@
+//! Bounded, dependency-aware worker pool for concurrent check execution
+
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use healthcheck_core::probes::{ProbeResult, elapsed_ms};
+use log::{info, warn};
+
+use super::retry::Attempted;
+use crate::plan::PlannedCheck;
+
+pub const DEADLINE_EXCEEDED: &str = "deadline exceeded";
+
+/// What happened to a single planned check
+pub enum Outcome {
+    Completed(Attempted),
+    /// Not run because a dependency failed or was itself skipped
+    Skipped(String),
+}
+
+impl Outcome {
+    fn is_ok(&self) -> bool {
+        matches!(self, Self::Completed(a) if a.result.ok)
+    }
+}
+
+/// Run every check on at most `concurrency` worker threads
+///
+/// The calling thread dispatches a check once all of its `depends_on`
+/// checks succeeded, and skips it as soon as one of them did not. Results
+/// are returned in input order.
+///
+/// With a `deadline`, checks still running (or not yet started) when it
+/// expires are abandoned and reported as failures. Workers are detached
+/// threads, so a probe stuck in DNS resolution cannot hold up the run.
+pub fn run_parallel(
+    jobs: Arc<Vec<PlannedCheck>>,
+    concurrency: usize,
+    deadline: Option<Duration>,
+) -> Vec<Outcome> {
+    let total = jobs.len();
+    let start = Instant::now();
+    let cutoff = deadline.map(|d| start + d);
+    let (job_tx, job_rx) = mpsc::channel::<usize>();
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let (done_tx, done_rx) = mpsc::channel();
+
+    for _ in 0..concurrency.clamp(1, total.max(1)) {
+        let jobs = Arc::clone(&jobs);
+        let job_rx = Arc::clone(&job_rx);
+        let done_tx = done_tx.clone();
+        thread::spawn(move || {
+            while let Ok(Ok(idx)) = job_rx.lock().map(|rx| rx.recv()) {
+                if cutoff.is_some_and(|c| Instant::now() >= c)
+                    || done_tx.send((idx, jobs[idx].run())).is_err()
+                {
+                    break;
+                }
+            }
+        });
+    }
+    drop(done_tx);
+
+    let mut outcomes: Vec<Option<Outcome>> = (0..total).map(|_| None).collect();
+    let mut dispatched = vec![false; total];
+    let mut finished = dispatch_ready(&jobs, &mut outcomes, &mut dispatched, &job_tx);
+
+    while finished < total {
+        let message = match cutoff {
+            Some(c) => done_rx
+                .recv_timeout(c.saturating_duration_since(Instant::now()))
+                .ok(),
+            None => done_rx.recv().ok(),
+        };
+        let Some((idx, attempted)) = message else {
+            break;
+        };
+        outcomes[idx] = Some(Outcome::Completed(attempted));
+        finished += 1 + dispatch_ready(&jobs, &mut outcomes, &mut dispatched, &job_tx);
+    }
+
+    let abandoned_latency = elapsed_ms(start);
+    outcomes
+        .into_iter()
+        .enumerate()
+        .map(|(idx, slot)| slot.unwrap_or_else(|| abandoned(&jobs[idx], abandoned_latency)))
+        .collect()
+}
+
+/// Failure reported for a check still running when its deadline expired
+pub fn abandoned(check: &PlannedCheck, latency_ms: u64) -> Outcome {
+    warn!("Check '{}' abandoned: {DEADLINE_EXCEEDED}", check.name);
+    Outcome::Completed(Attempted {
+        result: ProbeResult::failure(latency_ms, DEADLINE_EXCEEDED),
+        attempts: 1,
+        attempt_errors: vec![DEADLINE_EXCEEDED.to_string()],
+    })
+}
+
+/// Dispatch checks whose dependencies all passed and skip those with a
+/// failed dependency, repeating until nothing changes
+///
+/// Returns how many checks were skipped (and are therefore finished).
+fn dispatch_ready(
+    jobs: &[PlannedCheck],
+    outcomes: &mut [Option<Outcome>],
+    dispatched: &mut [bool],
+    job_tx: &Sender<usize>,
+) -> usize {
+    let mut skipped = 0;
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+        for idx in 0..jobs.len() {
+            if dispatched[idx] || outcomes[idx].is_some() {
+                continue;
+            }
+
+            let deps = &jobs[idx].deps;
+            if let Some(&dep) = deps
+                .iter()
+                .find(|&&d| outcomes[d].as_ref().is_some_and(|o| !o.is_ok()))
+            {
+                let verb = match outcomes[dep] {
+                    Some(Outcome::Skipped(_)) => "skipped",
+                    _ => "failed",
+                };
+                let reason = format!("dependency {} {verb}", jobs[dep].name);
+                info!("Skipping check '{}': {reason}", jobs[idx].name);
+                outcomes[idx] = Some(Outcome::Skipped(reason));
+                skipped += 1;
+                changed = true;
+            } else if deps.iter().all(|&d| outcomes[d].is_some()) {
+                dispatched[idx] = true;
+                let _ = job_tx.send(idx);
+            }
+        }
+    }
+
+    skipped
+}
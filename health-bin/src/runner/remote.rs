@@ -0,0 +1,84 @@
+//! `--from unix:PATH`: report the results of a running `serve` instance
+//!
+//! Instead of probing anything itself, the CLI asks the server's `/health`
+//! endpoint over its Unix socket, prints the JSON unchanged and exits with
+//! the code the document's `"status"` maps to. A 503 without a status, such
+//! as a draining server's error, counts as unhealthy.
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::time::Duration;
+
+use super::RunOptions;
+use crate::server::read_token;
+use crate::status::{OverallStatus, print_error_json};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+pub fn report_from_server(socket: &Path, options: &RunOptions) {
+    let exit_codes = options.exit_codes();
+    let token = options.from_token.as_ref().map(read_token).transpose();
+    match token.and_then(|token| fetch_health(socket, token.as_deref())) {
+        Ok((body, status)) => {
+            print!("{body}");
+            let code = exit_codes.for_status(status);
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+        Err(e) => {
+            print_error_json(&e);
+            std::process::exit(exit_codes.config_error());
+        }
+    }
+}
+
+/// `GET /health` over the socket: the body and the overall status in it
+fn fetch_health(socket: &Path, token: Option<&str>) -> Result<(String, OverallStatus), String> {
+    let mut stream = UnixStream::connect(socket)
+        .map_err(|e| format!("Failed to connect to {}: {}", socket.display(), e))?;
+    let _ = stream.set_read_timeout(Some(TIMEOUT));
+    let _ = stream.set_write_timeout(Some(TIMEOUT));
+
+    let authorization =
+        token.map_or_else(String::new, |t| format!("Authorization: Bearer {t}\r\n"));
+    let request = format!(
+        "GET /health HTTP/1.1\r\nHost: localhost\r\n{authorization}Connection: close\r\n\r\n"
+    );
+    let mut response = String::new();
+    stream
+        .write_all(request.as_bytes())
+        .and_then(|()| stream.read_to_string(&mut response))
+        .map_err(|e| format!("Failed to query {}: {}", socket.display(), e))?;
+
+    let (code, body) = parse_response(&response)?;
+    if code != 200 && code != 503 {
+        return Err(format!("Server answered {}: {}", code, body.trim()));
+    }
+    let status = match body_status(body) {
+        Some(status) => status,
+        None if code == 503 => OverallStatus::Unhealthy,
+        None => return Err("Server response has no status".to_string()),
+    };
+    Ok((body.to_string(), status))
+}
+
+/// Status code and body of a `Connection: close` response
+fn parse_response(response: &str) -> Result<(u16, &str), String> {
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or("Malformed response from server")?;
+    let code = head
+        .split(' ')
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or("Malformed response from server")?;
+    Ok((code, body))
+}
+
+/// The top-level `"status"` comes before any check in the document
+fn body_status(body: &str) -> Option<OverallStatus> {
+    let rest = &body[body.find("\"status\": \"")? + "\"status\": \"".len()..];
+    OverallStatus::parse(&rest[..rest.find('"')?])
+}
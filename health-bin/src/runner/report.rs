@@ -0,0 +1,77 @@
+//! Turning pool outcomes into the reported results
+
+use healthcheck_core::probes::ProbeResult;
+
+use super::pool::Outcome;
+use super::retry::Attempted;
+use crate::plan::{PlannedCheck, PlannedGroup};
+use crate::status::{CheckResult, GroupResult, ReportEntry};
+
+/// Build the `checks` array: ungrouped checks in config order, each group
+/// at the position of its first member
+///
+/// `results` holds one result per plan entry, in plan order.
+pub fn assemble_report(
+    plan: &[PlannedCheck],
+    groups: &[PlannedGroup],
+    results: Vec<CheckResult>,
+) -> Vec<ReportEntry> {
+    let mut results: Vec<Option<CheckResult>> = results.into_iter().map(Some).collect();
+
+    let mut entries = Vec::with_capacity(plan.len());
+    for idx in 0..plan.len() {
+        match groups.iter().find(|g| g.members.first() == Some(&idx)) {
+            Some(group) => entries.push(ReportEntry::Group(GroupResult {
+                name: group.name.clone(),
+                severity: group.severity,
+                min_ok: group.min_ok,
+                members: group
+                    .members
+                    .iter()
+                    .filter_map(|&m| results[m].take())
+                    .collect(),
+            })),
+            None if plan[idx].options.group.is_none() => {
+                if let Some(result) = results[idx].take() {
+                    entries.push(ReportEntry::Check(result));
+                }
+            }
+            None => {}
+        }
+    }
+    entries
+}
+
+pub fn to_result(check: &PlannedCheck, outcome: Outcome) -> CheckResult {
+    let (outcome, skipped) = match outcome {
+        Outcome::Completed(attempted) => (attempted, None),
+        Outcome::Skipped(reason) => (
+            Attempted {
+                result: ProbeResult {
+                    ok: false,
+                    latency_ms: 0,
+                    error: None,
+                },
+                attempts: 0,
+                attempt_errors: Vec::new(),
+            },
+            Some(reason),
+        ),
+    };
+
+    let warning = check.options.latency.warning(&outcome.result);
+    CheckResult {
+        name: check.name.clone(),
+        check_type: check.check_type.clone(),
+        target: check.target.clone(),
+        severity: check.options.severity,
+        ok: outcome.result.ok,
+        latency_ms: outcome.result.latency_ms,
+        error: outcome.result.error,
+        attempts: outcome.attempts,
+        attempt_errors: outcome.attempt_errors,
+        skipped,
+        warning,
+        streak: None,
+    }
+}
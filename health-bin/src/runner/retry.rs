@@ -0,0 +1,123 @@
+//! Generic per-check retry policy
+//!
+//! Any probe type accepts `retries=`, `retry_delay_ms=` and `retry_backoff=`.
+//! A check is attempted up to `retries + 1` times, sleeping between attempts
+//! for `retry_delay_ms` multiplied by `retry_backoff` after every failure,
+//! up to `MAX_RETRY_DELAY_MS`.
+
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+use healthcheck_core::{probes::ProbeResult, registry::HealthCheck};
+use log::warn;
+
+const DEFAULT_RETRY_DELAY_MS: u64 = 200;
+const DEFAULT_RETRY_BACKOFF: f64 = 1.0;
+const MAX_RETRIES: u32 = 10;
+const MAX_RETRY_DELAY_MS: u64 = 60_000;
+const MAX_RETRY_BACKOFF: f64 = 10.0;
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    retries: u32,
+    delay: Duration,
+    backoff: f64,
+}
+
+/// Final result of a check plus the history of failed attempts
+pub struct Attempted {
+    pub result: ProbeResult,
+    pub attempts: u32,
+    pub attempt_errors: Vec<String>,
+}
+
+impl RetryPolicy {
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        let retries = match params.get("retries") {
+            Some(v) => match v.parse::<u32>() {
+                Ok(n) if n <= MAX_RETRIES => n,
+                _ => return Err(format!("retries must be 0-{MAX_RETRIES}, got '{v}'")),
+            },
+            None => 0,
+        };
+
+        let delay_ms = match params.get("retry_delay_ms") {
+            Some(v) => match v.parse::<u64>() {
+                Ok(ms) if ms <= MAX_RETRY_DELAY_MS => ms,
+                _ => {
+                    return Err(format!(
+                        "retry_delay_ms must be 0-{MAX_RETRY_DELAY_MS}, got '{v}'"
+                    ));
+                }
+            },
+            None => DEFAULT_RETRY_DELAY_MS,
+        };
+
+        let backoff = match params.get("retry_backoff") {
+            Some(v) => match v.parse::<f64>() {
+                Ok(b) if (1.0..=MAX_RETRY_BACKOFF).contains(&b) => b,
+                _ => {
+                    return Err(format!(
+                        "retry_backoff must be a number from 1 to {MAX_RETRY_BACKOFF}, got '{v}'"
+                    ));
+                }
+            },
+            None => DEFAULT_RETRY_BACKOFF,
+        };
+
+        Ok(Self {
+            retries,
+            delay: Duration::from_millis(delay_ms),
+            backoff,
+        })
+    }
+
+    /// Run the check called `name` until it succeeds or the retry budget
+    /// is spent
+    pub fn run(&self, name: &str, check: &dyn HealthCheck) -> Attempted {
+        let mut attempt_errors = Vec::new();
+        let mut delay = self.delay;
+        let mut attempts = 1;
+
+        loop {
+            let result = check.check();
+            if result.ok || attempts > self.retries {
+                if !result.ok {
+                    attempt_errors.push(result.error.clone().unwrap_or_default());
+                }
+                return Attempted {
+                    result,
+                    attempts,
+                    attempt_errors,
+                };
+            }
+
+            let err = result.error.unwrap_or_default();
+            warn!(
+                "Check '{name}' attempt {attempts} failed: {err}; retrying in {}ms",
+                delay.as_millis()
+            );
+            attempt_errors.push(err);
+            thread::sleep(delay);
+            delay = next_delay(delay, self.backoff);
+            attempts += 1;
+        }
+    }
+}
+
+/// `delay` grown by `backoff`, saturating at `MAX_RETRY_DELAY_MS`
+pub(super) fn next_delay(delay: Duration, backoff: f64) -> Duration {
+    let max = Duration::from_millis(MAX_RETRY_DELAY_MS);
+    Duration::try_from_secs_f64(delay.as_secs_f64() * backoff).map_or(max, |next| next.min(max))
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: 0,
+            delay: Duration::from_millis(DEFAULT_RETRY_DELAY_MS),
+            backoff: DEFAULT_RETRY_BACKOFF,
+        }
+    }
+}
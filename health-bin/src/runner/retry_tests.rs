@@ -0,0 +1,113 @@
+use super::*;
+use healthcheck_core::{probes::ProbeResult, registry::HealthCheck};
+use retry::next_delay;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+/// Fails until it has been called `succeed_on` times
+struct FlakyCheck {
+    calls: AtomicU32,
+    succeed_on: u32,
+}
+
+impl HealthCheck for FlakyCheck {
+    fn check(&self) -> ProbeResult {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        if call >= self.succeed_on {
+            ProbeResult::success(1)
+        } else {
+            ProbeResult::failure(1, format!("refused #{call}"))
+        }
+    }
+
+    fn name(&self) -> &str {
+        "flaky"
+    }
+}
+
+fn flaky(succeed_on: u32) -> FlakyCheck {
+    FlakyCheck {
+        calls: AtomicU32::new(0),
+        succeed_on,
+    }
+}
+
+fn retry_params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_retry_recovers_from_transient_failure() {
+    let policy =
+        RetryPolicy::from_params(&retry_params(&[("retries", "2"), ("retry_delay_ms", "1")]))
+            .unwrap();
+
+    let outcome = policy.run("flaky", &flaky(2));
+    assert!(outcome.result.ok);
+    assert_eq!(outcome.attempts, 2);
+    assert_eq!(outcome.attempt_errors, vec!["refused #1".to_string()]);
+}
+
+#[test]
+fn test_retry_exhausted_reports_every_attempt() {
+    let policy = RetryPolicy::from_params(&retry_params(&[
+        ("retries", "2"),
+        ("retry_delay_ms", "1"),
+        ("retry_backoff", "2"),
+    ]))
+    .unwrap();
+
+    let outcome = policy.run("flaky", &flaky(10));
+    assert!(!outcome.result.ok);
+    assert_eq!(outcome.attempts, 3);
+    assert_eq!(outcome.attempt_errors.len(), 3);
+    assert_eq!(outcome.attempt_errors[2], "refused #3");
+}
+
+#[test]
+fn test_retry_defaults_to_single_attempt() {
+    let outcome = RetryPolicy::default().run("flaky", &flaky(2));
+    assert!(!outcome.result.ok);
+    assert_eq!(outcome.attempts, 1);
+}
+
+#[test]
+fn test_retry_rejects_invalid_params() {
+    assert!(RetryPolicy::from_params(&retry_params(&[("retries", "-1")])).is_err());
+    assert!(RetryPolicy::from_params(&retry_params(&[("retries", "99")])).is_err());
+    assert!(RetryPolicy::from_params(&retry_params(&[("retry_backoff", "0.5")])).is_err());
+    assert!(RetryPolicy::from_params(&retry_params(&[("retry_delay_ms", "x")])).is_err());
+}
+
+#[test]
+fn test_retry_bounds_delay_and_backoff() {
+    let params = |delay: &str, backoff: &str| {
+        RetryPolicy::from_params(&retry_params(&[
+            ("retries", "3"),
+            ("retry_delay_ms", delay),
+            ("retry_backoff", backoff),
+        ]))
+    };
+    assert!(params("60000", "10").is_ok());
+    assert!(params("60001", "1").is_err());
+    assert!(params("18446744073709551615", "1").is_err());
+    assert!(params("10", "10.5").is_err());
+    assert!(params("10", "1e300").is_err());
+    assert!(params("10", "inf").is_err());
+}
+
+#[test]
+fn test_retry_delay_saturates() {
+    let max = Duration::from_secs(60);
+    assert_eq!(
+        next_delay(Duration::from_millis(10), 2.0),
+        Duration::from_millis(20)
+    );
+    assert_eq!(next_delay(Duration::from_millis(50_000), 10.0), max);
+    assert_eq!(next_delay(Duration::from_millis(10), 1e300), max);
+    assert_eq!(next_delay(Duration::MAX, f64::MAX), max);
+}
@@ -0,0 +1,86 @@
+//! Fake checks and results shared by unit tests
+
+use super::*;
+use crate::plan::{CheckOptions, PlannedCheck};
+use crate::status::CheckResult;
+use healthcheck_core::{probes::ProbeResult, registry::HealthCheck};
+use pool::Outcome;
+use std::time::Duration;
+
+struct SleepCheck {
+    delay_ms: u64,
+    ok: bool,
+}
+
+impl HealthCheck for SleepCheck {
+    fn check(&self) -> ProbeResult {
+        std::thread::sleep(Duration::from_millis(self.delay_ms));
+        if self.ok {
+            ProbeResult::success(self.delay_ms)
+        } else {
+            ProbeResult::failure(self.delay_ms, "boom")
+        }
+    }
+
+    fn name(&self) -> &str {
+        "sleep"
+    }
+}
+
+struct PanicCheck;
+
+impl HealthCheck for PanicCheck {
+    fn check(&self) -> ProbeResult {
+        panic!("probe blew up")
+    }
+
+    fn name(&self) -> &str {
+        "panic"
+    }
+}
+
+pub(super) fn completed(outcome: &Outcome) -> &Attempted {
+    match outcome {
+        Outcome::Completed(attempted) => attempted,
+        Outcome::Skipped(reason) => panic!("unexpectedly skipped: {reason}"),
+    }
+}
+
+pub(super) fn sleep_check(delay_ms: u64, ok: bool) -> PlannedCheck {
+    PlannedCheck {
+        name: format!("sleep-{delay_ms}"),
+        check_type: "sleep".to_string(),
+        target: String::new(),
+        line: 1,
+        options: CheckOptions::default(),
+        probe: Ok(Box::new(SleepCheck { delay_ms, ok })),
+        deps: Vec::new(),
+    }
+}
+
+pub(super) fn panic_check() -> PlannedCheck {
+    PlannedCheck {
+        name: "panics".to_string(),
+        probe: Ok(Box::new(PanicCheck)),
+        ..sleep_check(0, true)
+    }
+}
+
+/// A passing or failing `tcp` result called "check"; tests adjust it with
+/// struct update syntax
+pub(crate) fn check_result(ok: bool) -> CheckResult {
+    CheckResult {
+        name: "check".to_string(),
+        check_type: "tcp".to_string(),
+        target: "localhost:1".to_string(),
+        severity: Default::default(),
+        ok,
+        latency_ms: 1,
+        error: None,
+        attempts: 1,
+        attempt_errors: Vec::new(),
+        skipped: None,
+        warning: None,
+        streak: None,
+    }
+}
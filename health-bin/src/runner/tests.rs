@@ -0,0 +1,117 @@
+use super::*;
+use std::time::{Duration, Instant};
+use test_support::{completed, panic_check, sleep_check};
+
+#[test]
+fn test_run_parallel_preserves_input_order() {
+    let checks = vec![
+        sleep_check(80, true),
+        sleep_check(10, false),
+        sleep_check(40, true),
+    ];
+
+    let results = pool::run_parallel(Arc::new(checks), 3, None);
+    let latencies: Vec<u64> = results
+        .iter()
+        .map(|r| completed(r).result.latency_ms)
+        .collect();
+    assert_eq!(latencies, vec![80, 10, 40]);
+    assert!(!completed(&results[1]).result.ok);
+}
+
+#[test]
+fn test_run_parallel_runs_concurrently() {
+    let checks: Vec<_> = (0..5).map(|_| sleep_check(100, true)).collect();
+
+    let start = Instant::now();
+    let results = pool::run_parallel(Arc::new(checks), 5, None);
+    assert_eq!(results.len(), 5);
+    assert!(
+        start.elapsed() < Duration::from_millis(400),
+        "checks should overlap, took {:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn test_run_parallel_respects_concurrency_bound() {
+    let checks: Vec<_> = (0..4).map(|_| sleep_check(50, true)).collect();
+
+    let start = Instant::now();
+    pool::run_parallel(Arc::new(checks), 1, None);
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+#[test]
+fn test_run_parallel_empty() {
+    assert!(pool::run_parallel(Arc::new(Vec::new()), 4, None).is_empty());
+}
+
+#[test]
+fn test_run_parallel_deadline_abandons_hung_checks() {
+    let checks = vec![sleep_check(10, true), sleep_check(5_000, true)];
+
+    let start = Instant::now();
+    let results = pool::run_parallel(Arc::new(checks), 2, Some(Duration::from_millis(200)));
+    assert!(start.elapsed() < Duration::from_millis(1_000));
+    assert!(completed(&results[0]).result.ok);
+    assert!(!completed(&results[1]).result.ok);
+    assert_eq!(
+        completed(&results[1]).result.error.as_deref(),
+        Some(pool::DEADLINE_EXCEEDED)
+    );
+}
+
+#[test]
+fn test_run_parallel_deadline_covers_unstarted_checks() {
+    let checks = vec![sleep_check(5_000, true), sleep_check(10, true)];
+
+    let results = pool::run_parallel(Arc::new(checks), 1, Some(Duration::from_millis(100)));
+    assert!(results.iter().all(|r| !completed(r).result.ok));
+}
+
+#[test]
+fn test_run_parallel_reports_panicking_checks() {
+    // One worker, so the panic must not take down the thread running the rest
+    let checks = vec![panic_check(), sleep_check(10, true)];
+    let results = pool::run_parallel(Arc::new(checks), 1, None);
+
+    let panicked = &completed(&results[0]).result;
+    assert!(!panicked.ok);
+    assert_eq!(
+        panicked.error.as_deref(),
+        Some("check panicked: probe blew up")
+    );
+    assert!(completed(&results[1]).result.ok);
+}
+
+#[test]
+fn test_merge_settings_cli_takes_precedence() {
+    let mut settings = std::collections::HashMap::new();
+    settings.insert("concurrency".to_string(), "2".to_string());
+    settings.insert("deadline_ms".to_string(), "1500".to_string());
+
+    let mut options = RunOptions {
+        concurrency: Some(6),
+        ..RunOptions::default()
+    };
+    options.merge_settings(&settings).unwrap();
+    assert_eq!(options.concurrency(), 6);
+    assert_eq!(options.deadline, Some(Duration::from_millis(1500)));
+
+    settings.insert("deadline_ms".to_string(), "soon".to_string());
+    assert!(RunOptions::default().merge_settings(&settings).is_err());
+}
+
+#[test]
+fn test_unknown_settings_are_rejected_with_their_line() {
+    let configs = healthcheck_core::config::parse_config_str(
+        "tcp:host=localhost,port=1\nsettings:concurrency=2,deadline=500\n",
+    )
+    .unwrap();
+    let err = RunOptions::check_settings_line(&configs[1]).unwrap_err();
+    assert!(
+        err.starts_with("line 2: unknown setting 'deadline'"),
+        "{err}"
+    );
+}
@@ -0,0 +1,125 @@
+//! Access control for the serve API
+//!
+//! Three independent layers: a source address allowlist (`--allow`), an
+//! optional bearer token (`--token-file` / `--token-env`) and public mode
+//! (`--public`), in which clients without the token still get the overall
+//! status code but no per-check details.
+
+mod cidr;
+
+pub use cidr::Cidr;
+
+use std::env;
+use std::fs;
+use std::net::IpAddr;
+
+use super::http::{Request, Response};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenSource {
+    File(String),
+    Env(String),
+}
+
+/// The trimmed token; fails if it is missing or empty
+pub fn read_token(source: &TokenSource) -> Result<String, String> {
+    let token = match source {
+        TokenSource::File(path) => fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read token file '{}': {}", path, e))?,
+        TokenSource::Env(var) => {
+            env::var(var).map_err(|_| format!("Token variable '{}' is not set", var))?
+        }
+    };
+
+    let token = token.trim();
+    if token.is_empty() {
+        return Err("Bearer token is empty".to_string());
+    }
+    Ok(token.to_string())
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuthOptions {
+    pub token: Option<TokenSource>,
+    pub allow: Vec<Cidr>,
+    pub public: bool,
+}
+
+/// What a client may see
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    Full,
+    /// Overall status only, no check names, targets or errors
+    StatusOnly,
+}
+
+pub struct AuthPolicy {
+    token: Option<String>,
+    allow: Vec<Cidr>,
+    public: bool,
+}
+
+impl AuthPolicy {
+    /// Resolve the token source; fails if it is missing or empty
+    pub fn from_options(options: &AuthOptions) -> Result<Self, String> {
+        Ok(Self {
+            token: options.token.as_ref().map(read_token).transpose()?,
+            allow: options.allow.clone(),
+            public: options.public,
+        })
+    }
+
+    /// Whether details are visible to clients without credentials
+    pub fn is_open(&self) -> bool {
+        self.token.is_none() && !self.public
+    }
+
+    /// Decide what the client at `peer` may see, or the response refusing it
+    ///
+    /// `peer` is `None` for Unix socket clients, which the allowlist does
+    /// not apply to.
+    pub fn check(&self, peer: Option<IpAddr>, request: &Request) -> Result<Access, Response> {
+        if let Some(ip) = peer
+            && !self.allow.is_empty()
+            && !self.allow.iter().any(|cidr| cidr.contains(ip))
+        {
+            return Err(Response::error(403, "forbidden"));
+        }
+
+        let Some(token) = &self.token else {
+            return Ok(if self.public {
+                Access::StatusOnly
+            } else {
+                Access::Full
+            });
+        };
+
+        let presented = request
+            .header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match presented {
+            Some(given) if constant_time_eq(given.trim().as_bytes(), token.as_bytes()) => {
+                Ok(Access::Full)
+            }
+            _ if self.public => Ok(Access::StatusOnly),
+            _ => Err(self.unauthorized()),
+        }
+    }
+
+    /// Response for a detail endpoint requested without full access
+    pub fn unauthorized(&self) -> Response {
+        match self.token {
+            Some(_) => Response::error(401, "unauthorized")
+                .with_header("WWW-Authenticate", "Bearer realm=\"healthcheck\""),
+            None => Response::error(403, "forbidden"),
+        }
+    }
+}
+
+/// Compare without returning early, so timing does not leak the token
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+use super::*;
+use std::net::IpAddr;
+
+fn ip(addr: &str) -> IpAddr {
+    addr.parse().unwrap()
+}
+
+fn request(authorization: Option<&str>) -> Request {
+    Request {
+        method: "GET".to_string(),
+        path: "/health".to_string(),
+        headers: authorization
+            .map(|value| vec![("authorization".to_string(), value.to_string())])
+            .unwrap_or_default(),
+        body: Vec::new(),
+    }
+}
+
+fn policy(token: Option<&str>, allow: &[&str], public: bool) -> AuthPolicy {
+    AuthPolicy {
+        token: token.map(str::to_string),
+        allow: allow.iter().map(|c| Cidr::parse(c).unwrap()).collect(),
+        public,
+    }
+}
+
+#[test]
+fn test_cidr_contains() {
+    let net = Cidr::parse("10.1.0.0/16").unwrap();
+    assert!(net.contains(ip("10.1.200.3")));
+    assert!(!net.contains(ip("10.2.0.1")));
+    // IPv4 clients of a dual-stack socket
+    assert!(net.contains(ip("::ffff:10.1.0.9")));
+
+    assert!(Cidr::parse("0.0.0.0/0").unwrap().contains(ip("192.0.2.1")));
+    assert!(Cidr::parse("fd00::/8").unwrap().contains(ip("fd12::1")));
+    assert!(!Cidr::parse("fd00::/8").unwrap().contains(ip("10.1.0.1")));
+
+    let single = Cidr::parse("127.0.0.1").unwrap();
+    assert!(single.contains(ip("127.0.0.1")));
+    assert!(!single.contains(ip("127.0.0.2")));
+}
+
+#[test]
+fn test_cidr_rejects_invalid_values() {
+    for invalid in [
+        "",
+        "10.0.0.0/33",
+        "::/129",
+        "10.0.0/8",
+        "host/8",
+        "10.0.0.0/x",
+    ] {
+        assert!(Cidr::parse(invalid).is_err(), "accepted '{invalid}'");
+    }
+}
+
+#[test]
+fn test_allowlist_refuses_other_sources() {
+    let auth = policy(None, &["10.0.0.0/8"], false);
+    assert_eq!(
+        auth.check(Some(ip("10.0.0.1")), &request(None)).ok(),
+        Some(Access::Full)
+    );
+    assert_eq!(
+        auth.check(Some(ip("192.0.2.1")), &request(None))
+            .unwrap_err()
+            .status,
+        403
+    );
+    // Unix socket clients have no address
+    assert_eq!(auth.check(None, &request(None)).ok(), Some(Access::Full));
+}
+
+#[test]
+fn test_bearer_token_required() {
+    let auth = policy(Some("s3cret"), &[], false);
+    let peer = Some(ip("127.0.0.1"));
+    assert_eq!(
+        auth.check(peer, &request(Some("Bearer s3cret"))).ok(),
+        Some(Access::Full)
+    );
+
+    for bad in [
+        None,
+        Some("Bearer wrong"),
+        Some("Basic s3cret"),
+        Some("Bearer s3cre"),
+    ] {
+        let refused = auth.check(peer, &request(bad)).unwrap_err();
+        assert_eq!(refused.status, 401);
+        assert!(
+            refused
+                .headers
+                .iter()
+                .any(|(name, _)| *name == "WWW-Authenticate")
+        );
+    }
+}
+
+#[test]
+fn test_public_mode_hides_details_without_token() {
+    let peer = Some(ip("127.0.0.1"));
+    let open = policy(None, &[], true);
+    assert_eq!(
+        open.check(peer, &request(None)).ok(),
+        Some(Access::StatusOnly)
+    );
+
+    let with_token = policy(Some("s3cret"), &[], true);
+    assert_eq!(
+        with_token.check(peer, &request(None)).ok(),
+        Some(Access::StatusOnly)
+    );
+    assert_eq!(
+        with_token.check(peer, &request(Some("Bearer s3cret"))).ok(),
+        Some(Access::Full)
+    );
+}
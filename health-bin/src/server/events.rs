@@ -0,0 +1,112 @@
+//! `/events`: server-sent events stream of check state transitions
+//!
+//! A new client first gets one `replay` event per check with its current
+//! state, then a `transition` event whenever a check's state changes. Both
+//! carry the same JSON document; `old` is null in replays.
+
+use std::io::Write;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Duration;
+
+use log::debug;
+
+use super::App;
+use super::auth::Access;
+use super::http::{self, Request, Response};
+use super::listener::Conn;
+use crate::monitor::Transition;
+use crate::status::escape_json_string;
+
+/// Comment lines keep proxies from timing out idle streams and notice
+/// clients that went away
+const KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// Streams open at once. They last as long as their client, so they have
+/// their own budget instead of holding connections that probes need
+const MAX_STREAMS: usize = 8;
+
+pub fn stream_events(conn: &mut Conn, request: &Request, app: &App, access: Access) {
+    let refused = match (request.method.as_str(), access) {
+        ("GET", Access::Full) => None,
+        ("GET", Access::StatusOnly) => Some(app.auth.unauthorized()),
+        _ => Some(Response::error(405, "method not allowed").with_header("Allow", "GET")),
+    };
+    if let Some(response) = refused {
+        let _ = http::write_response(conn, &response, false);
+        return;
+    }
+    let Some(_slot) = StreamSlot::take(app) else {
+        let busy = Response::error(503, "too many event streams");
+        let _ = http::write_response(conn, &busy, false);
+        return;
+    };
+
+    // Subscribe before the replay so no transition falls in between
+    let monitor = app.live.monitor();
+    let transitions = monitor.events().subscribe();
+    let replay: Vec<Transition> = monitor.with_states(|states| {
+        monitor
+            .plan()
+            .iter()
+            .zip(states)
+            .map(|(check, state)| Transition::new(&check.name, None, state.latest.as_ref()))
+            .collect()
+    });
+
+    let head = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n";
+    let mut sent = conn.write_all(head.as_bytes());
+    for transition in replay {
+        sent = sent.and_then(|()| write_event(conn, "replay", &transition));
+    }
+
+    while sent.is_ok() {
+        sent = match transitions.recv_timeout(KEEPALIVE) {
+            Ok(transition) => write_event(conn, "transition", &transition),
+            Err(RecvTimeoutError::Timeout) => conn.write_all(b": keepalive\n\n"),
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        .and_then(|()| conn.flush());
+    }
+    debug!("Event stream closed");
+}
+
+/// Moves a connection from the request budget to the stream budget
+struct StreamSlot<'a>(&'a App);
+
+impl<'a> StreamSlot<'a> {
+    fn take(app: &'a App) -> Option<Self> {
+        if app.streams.fetch_add(1, Ordering::SeqCst) >= MAX_STREAMS {
+            app.streams.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        app.active.fetch_sub(1, Ordering::SeqCst);
+        Some(Self(app))
+    }
+}
+
+impl Drop for StreamSlot<'_> {
+    fn drop(&mut self) {
+        self.0.active.fetch_add(1, Ordering::SeqCst);
+        self.0.streams.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn write_event(conn: &mut Conn, event: &str, transition: &Transition) -> std::io::Result<()> {
+    let optional = |value: Option<&str>| {
+        value.map_or_else(
+            || "null".to_string(),
+            |value| format!("\"{}\"", escape_json_string(value)),
+        )
+    };
+    let data = format!(
+        "{{\"name\": \"{}\", \"old\": {}, \"new\": \"{}\", \"error\": {}, \"latency_ms\": {}, \"timestamp_ms\": {}}}",
+        escape_json_string(&transition.name),
+        optional(transition.old),
+        transition.new,
+        optional(transition.error.as_deref()),
+        transition.latency_ms,
+        transition.timestamp_ms,
+    );
+    write!(conn, "event: {event}\ndata: {data}\n\n")
+}
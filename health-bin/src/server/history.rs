@@ -0,0 +1,38 @@
+//! `/checks/{name}/history`: a check's recent results, oldest first
+
+use std::fmt::Write;
+
+use crate::monitor::History;
+use crate::status::escape_json_string;
+
+pub fn render_history(name: &str, history: &History) -> String {
+    let mut out = String::with_capacity(128 + 128 * history.len());
+    out.push_str("{\n");
+    let _ = writeln!(out, "  \"name\": \"{}\",", escape_json_string(name));
+    let _ = writeln!(out, "  \"capacity\": {},", history.capacity());
+    out.push_str("  \"history\": [\n");
+
+    for (i, entry) in history.entries().enumerate() {
+        let comma = if i + 1 < history.len() { "," } else { "" };
+        let _ = writeln!(
+            out,
+            "    {{\"timestamp_ms\": {}, \"ok\": {}, \"latency_ms\": {}, \"error\": {}, \"skipped\": {}}}{comma}",
+            entry.timestamp_ms,
+            entry.ok,
+            entry.latency_ms,
+            optional(entry.error.as_deref()),
+            optional(entry.skipped.as_deref()),
+        );
+    }
+
+    out.push_str("  ]\n");
+    out.push_str("}\n");
+    out
+}
+
+fn optional(value: Option<&str>) -> String {
+    value.map_or_else(
+        || "null".to_string(),
+        |value| format!("\"{}\"", escape_json_string(value)),
+    )
+}
@@ -0,0 +1,98 @@
+//! Reading requests from and writing responses to a connection
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use super::{Request, Response, reason};
+
+const MAX_HEADER_BYTES: usize = 8 * 1024;
+const MAX_HEADERS: usize = 64;
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Read one request; the error is suitable for a 400 response
+pub fn read_request(stream: impl Read) -> Result<Request, String> {
+    let mut reader = BufReader::new(stream.take((MAX_HEADER_BYTES + MAX_BODY_BYTES) as u64));
+    let mut header_bytes = 0;
+    let mut next_line = |reader: &mut BufReader<_>| -> Result<String, String> {
+        let mut line = String::new();
+        let read = reader
+            .read_line(&mut line)
+            .map_err(|e| format!("read failed: {e}"))?;
+        header_bytes += read;
+        if read == 0 || header_bytes > MAX_HEADER_BYTES {
+            return Err("incomplete or oversized request head".to_string());
+        }
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    };
+
+    let request_line = next_line(&mut reader)?;
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(target), Some(version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err("malformed request line".to_string());
+    };
+    if !version.starts_with("HTTP/1.") || !target.starts_with('/') {
+        return Err("malformed request line".to_string());
+    }
+    let path = target.split_once('?').map_or(target, |(path, _)| path);
+
+    let mut headers = Vec::new();
+    loop {
+        let line = next_line(&mut reader)?;
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':').ok_or("malformed header")?;
+        if headers.len() == MAX_HEADERS {
+            return Err("too many headers".to_string());
+        }
+        headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+    }
+
+    let mut request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers,
+        body: Vec::new(),
+    };
+
+    let length = match request.header("content-length") {
+        Some(value) => value
+            .parse::<usize>()
+            .map_err(|_| "invalid content-length")?,
+        None => 0,
+    };
+    if length > MAX_BODY_BYTES {
+        return Err("request body too large".to_string());
+    }
+    request.body = vec![0; length];
+    reader
+        .read_exact(&mut request.body)
+        .map_err(|e| format!("read failed: {e}"))?;
+
+    Ok(request)
+}
+
+/// Write a complete response; `head_only` omits the body (HEAD requests)
+pub fn write_response(
+    mut stream: impl Write,
+    response: &Response,
+    head_only: bool,
+) -> io::Result<()> {
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n",
+        response.status,
+        reason(response.status),
+        response.content_type,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    if !head_only {
+        stream.write_all(response.body.as_bytes())?;
+    }
+    stream.flush()
+}
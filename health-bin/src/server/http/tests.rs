@@ -0,0 +1,65 @@
+use super::*;
+
+fn parse(raw: &str) -> Result<Request, String> {
+    read_request(raw.as_bytes())
+}
+
+#[test]
+fn test_read_request_parses_path_and_headers() {
+    let request =
+        parse("GET /health?verbose=1 HTTP/1.1\r\nHost: x\r\nX-Token:  abc \r\n\r\n").unwrap();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/health");
+    assert_eq!(request.header("host"), Some("x"));
+    assert_eq!(request.header("x-token"), Some("abc"));
+    assert!(request.body.is_empty());
+}
+
+#[test]
+fn test_read_request_reads_content_length_body() {
+    let request = parse("POST /run HTTP/1.1\r\nContent-Length: 4\r\n\r\nbodyextra").unwrap();
+    assert_eq!(request.body, b"body");
+}
+
+#[test]
+fn test_read_request_rejects_malformed_input() {
+    for raw in [
+        "",
+        "GET\r\n\r\n",
+        "GET health HTTP/1.1\r\n\r\n",
+        "GET /health SPDY/3\r\n\r\n",
+        "GET /health HTTP/1.1\r\nno-colon\r\n\r\n",
+        "GET /health HTTP/1.1\r\nHost: x\r\n",
+        "POST /run HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort",
+    ] {
+        assert!(parse(raw).is_err(), "accepted {raw:?}");
+    }
+
+    let huge = format!("GET /health HTTP/1.1\r\nX: {}\r\n\r\n", "a".repeat(9000));
+    assert!(parse(&huge).is_err());
+}
+
+#[test]
+fn test_write_response_head_only_omits_body() {
+    let response = Response::json(503, "{}").with_header("Allow", "GET");
+    let mut full = Vec::new();
+    write_response(&mut full, &response, false).unwrap();
+    let full = String::from_utf8(full).unwrap();
+    assert!(full.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+    assert!(full.contains("Content-Length: 2\r\n"));
+    assert!(full.contains("Allow: GET\r\n"));
+    assert!(full.ends_with("\r\n\r\n{}"));
+
+    let mut head = Vec::new();
+    write_response(&mut head, &response, true).unwrap();
+    assert!(String::from_utf8(head).unwrap().ends_with("\r\n\r\n"));
+}
+
+#[test]
+fn test_error_escapes_message() {
+    let response = Response::error(404, "no check named \"a\\b\"");
+    assert_eq!(
+        response.body,
+        "{\"error\": \"no check named \\\"a\\\\b\\\"\"}\n"
+    );
+}
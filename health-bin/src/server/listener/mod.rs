@@ -0,0 +1,118 @@
+//! Listening on a TCP address or a Unix domain socket
+
+mod conn;
+
+pub use conn::Conn;
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::net::TcpListener;
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+
+/// Permissions of a socket created without `--socket-mode`
+pub const DEFAULT_SOCKET_MODE: u32 = 0o660;
+
+unsafe extern "C" {
+    fn umask(mask: u32) -> u32;
+}
+
+/// Where to listen: `HOST:PORT` or `unix:PATH`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Listen {
+    Tcp(String),
+    Unix(PathBuf),
+}
+
+impl Listen {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.strip_prefix("unix:") {
+            Some("") => Err("unix: listen address requires a socket path".to_string()),
+            Some(path) => Ok(Self::Unix(PathBuf::from(path))),
+            None => Ok(Self::Tcp(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Listen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp(addr) => write!(f, "http://{addr}"),
+            Self::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// Octal file mode such as `660` or `0o600`
+pub fn parse_socket_mode(value: &str) -> Result<u32, String> {
+    let digits = value.strip_prefix("0o").unwrap_or(value);
+    match u32::from_str_radix(digits, 8) {
+        Ok(mode) if mode <= 0o777 => Ok(mode),
+        _ => Err(format!("socket mode must be octal like 660, got '{value}'")),
+    }
+}
+
+pub enum Listener {
+    Tcp(TcpListener),
+    Unix(UnixListener),
+}
+
+impl Listener {
+    /// Bind `listen`; a Unix socket gets `socket_mode` permissions
+    pub fn bind(listen: &Listen, socket_mode: u32) -> Result<Self, String> {
+        let failed = |e: io::Error| format!("Failed to listen on {listen}: {e}");
+        match listen {
+            Listen::Tcp(addr) => TcpListener::bind(addr).map(Self::Tcp).map_err(failed),
+            Listen::Unix(path) => {
+                remove_stale_socket(path)
+                    .map_err(|e| format!("Failed to listen on {listen}: {e}"))?;
+                let listener = bind_with_mode(path, socket_mode).map_err(failed)?;
+                fs::set_permissions(path, fs::Permissions::from_mode(socket_mode))
+                    .map_err(failed)?;
+                Ok(Self::Unix(listener))
+            }
+        }
+    }
+
+    pub fn accept(&self) -> io::Result<Conn> {
+        match self {
+            Self::Tcp(listener) => listener.accept().map(|(stream, _)| Conn::Tcp(stream)),
+            Self::Unix(listener) => listener.accept().map(|(stream, _)| Conn::Unix(stream)),
+        }
+    }
+
+    /// Whether only clients on this host can connect
+    pub fn is_local(&self) -> bool {
+        match self {
+            Self::Tcp(listener) => listener.local_addr().is_ok_and(|a| a.ip().is_loopback()),
+            Self::Unix(_) => true,
+        }
+    }
+}
+
+/// Create the socket with `mode` already applied, so it is never reachable
+/// with looser permissions between `bind` and the `chmod` after it
+fn bind_with_mode(path: &Path, mode: u32) -> io::Result<UnixListener> {
+    // SAFETY: umask only swaps the process file mode mask
+    let previous = unsafe { umask(!mode & 0o777) };
+    let listener = UnixListener::bind(path);
+    // SAFETY: as above
+    unsafe { umask(previous) };
+    listener
+}
+
+/// A socket left behind by a previous run is removed; a live one is not
+fn remove_stale_socket(path: &Path) -> Result<(), String> {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return Ok(());
+    };
+    if !metadata.file_type().is_socket() {
+        return Err("path exists and is not a socket".to_string());
+    }
+    if UnixStream::connect(path).is_ok() {
+        return Err("another server is listening on it".to_string());
+    }
+    fs::remove_file(path).map_err(|e| format!("cannot remove stale socket: {e}"))
+}
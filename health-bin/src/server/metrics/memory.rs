@@ -0,0 +1,33 @@
+//! Memory gauges of `/metrics`
+
+use healthcheck_core::memory::get_memory_stats;
+
+use super::gauge;
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// Container (cgroup) or host figures; omitted when neither is readable
+pub fn write_memory(out: &mut String) {
+    let (used_mb, total_mb, percent) = get_memory_stats();
+    if total_mb == 0 {
+        return;
+    }
+    gauge(
+        out,
+        "healthcheck_memory_used_bytes",
+        "Memory in use",
+        used_mb * BYTES_PER_MB,
+    );
+    gauge(
+        out,
+        "healthcheck_memory_limit_bytes",
+        "Memory limit or total",
+        total_mb * BYTES_PER_MB,
+    );
+    gauge(
+        out,
+        "healthcheck_memory_used_ratio",
+        "Fraction of the memory limit in use",
+        f64::from(percent) / 100.0,
+    );
+}
@@ -0,0 +1,139 @@
+//! Prometheus text exposition format (version 0.0.4) for `/metrics`
+
+use std::fmt::{Display, Write};
+
+use crate::monitor::{CheckState, LATENCY_BUCKETS, Monitor};
+use crate::plan::PlannedCheck;
+use memory::write_memory;
+
+mod memory;
+
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+type Series = [(String, CheckState)];
+
+pub fn render_metrics(monitor: &Monitor) -> String {
+    let checks: Vec<(String, CheckState)> = monitor
+        .plan()
+        .iter()
+        .map(labels)
+        .zip(monitor.states())
+        .collect();
+    let mut out = String::with_capacity(1024 + 1024 * checks.len());
+
+    per_check(
+        &mut out,
+        &checks,
+        "healthcheck_up",
+        "gauge",
+        "Whether the check is reported passing, after rise= and fall=",
+        |s| u64::from(s.latest.as_ref().is_some_and(|r| r.ok)),
+    );
+    write_latency(&mut out, &checks);
+    per_check(
+        &mut out,
+        &checks,
+        "healthcheck_runs_total",
+        "counter",
+        "Completed check runs",
+        |s| s.runs,
+    );
+    per_check(
+        &mut out,
+        &checks,
+        "healthcheck_failures_total",
+        "counter",
+        "Failed check runs",
+        |s| s.failures,
+    );
+    per_check(
+        &mut out,
+        &checks,
+        "healthcheck_consecutive_successes",
+        "gauge",
+        "Passing runs in a row, counted towards rise=",
+        |s| s.consecutive_successes,
+    );
+    per_check(
+        &mut out,
+        &checks,
+        "healthcheck_consecutive_failures",
+        "gauge",
+        "Failed runs in a row, counted towards fall=",
+        |s| s.consecutive_failures,
+    );
+
+    let up = monitor.snapshot().status.is_up();
+    gauge(
+        &mut out,
+        "healthcheck_overall_up",
+        "Whether the overall status is healthy or degraded",
+        u8::from(up),
+    );
+
+    write_memory(&mut out);
+    out
+}
+
+fn per_check(
+    out: &mut String,
+    checks: &Series,
+    name: &str,
+    kind: &str,
+    help: &str,
+    value: impl Fn(&CheckState) -> u64,
+) {
+    header(out, name, kind, help);
+    for (labels, state) in checks {
+        let _ = writeln!(out, "{name}{{{labels}}} {}", value(state));
+    }
+}
+
+fn write_latency(out: &mut String, checks: &Series) {
+    let name = "healthcheck_latency_seconds";
+    header(out, name, "histogram", "Latency of completed check runs");
+
+    for (labels, state) in checks {
+        let latency = &state.latency;
+        for (bound, count) in LATENCY_BUCKETS.iter().zip(latency.buckets) {
+            let _ = writeln!(out, "{name}_bucket{{{labels},le=\"{bound}\"}} {count}");
+        }
+        let _ = writeln!(
+            out,
+            "{name}_bucket{{{labels},le=\"+Inf\"}} {}",
+            latency.count
+        );
+        let _ = writeln!(
+            out,
+            "{name}_sum{{{labels}}} {}",
+            latency.sum_ms as f64 / 1000.0
+        );
+        let _ = writeln!(out, "{name}_count{{{labels}}} {}", latency.count);
+    }
+}
+
+fn gauge(out: &mut String, name: &str, help: &str, value: impl Display) {
+    header(out, name, "gauge", help);
+    let _ = writeln!(out, "{name} {value}");
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn labels(check: &PlannedCheck) -> String {
+    format!(
+        "name=\"{}\",type=\"{}\"",
+        escape_label(&check.name),
+        escape_label(&check.check_type)
+    )
+}
+
+/// Escape a label value: backslash, double quote and line feed
+pub fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,148 @@
+//! `serve`: HTTP API over continuously refreshed check results
+//!
+//! Checks run in the background on their own schedules while a small
+//! thread-per-connection HTTP/1.1 server answers from the latest results,
+//! so polling never waits for a probe.
+
+mod auth;
+mod events;
+mod history;
+mod http;
+mod listener;
+mod metrics;
+mod notify;
+mod options;
+mod page;
+mod routes;
+mod shutdown;
+
+pub use auth::{Cidr, TokenSource, read_token};
+pub use listener::{Listen, parse_socket_mode};
+pub use options::ServeOptions;
+#[cfg(test)]
+pub use options::{DEFAULT_INTERVAL, DEFAULT_LISTEN};
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, info, warn};
+
+use crate::monitor::{Live, spawn_reload_watcher};
+use crate::runner::init_logger;
+use auth::AuthPolicy;
+use http::Response;
+use listener::{Conn, DEFAULT_SOCKET_MODE, Listener};
+use notify::spawn_notifier;
+use shutdown::spawn_shutdown_watcher;
+
+/// Requests served at once; further clients get an immediate 503. `/events`
+/// streams have their own budget
+const MAX_CONNECTIONS: usize = 64;
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Everything a connection handler needs
+struct App {
+    live: Arc<Live>,
+    auth: AuthPolicy,
+    /// Set on SIGTERM: readiness fails while the process drains
+    draining: AtomicBool,
+    /// Connections being served, not counting event streams
+    active: AtomicUsize,
+    /// Open `/events` streams
+    streams: AtomicUsize,
+}
+
+/// Load the config, start the check rounds and serve until killed
+///
+/// SIGHUP (and with `--watch-config` a changed config file) reloads the
+/// checks without interrupting the API; SIGTERM drains and exits.
+pub fn serve(config_path: &str, options: ServeOptions) -> Result<(), String> {
+    init_logger();
+
+    let auth = AuthPolicy::from_options(&options.auth)?;
+    let live = Arc::new(Live::start(
+        config_path,
+        options.run,
+        options.interval,
+        options.history,
+    )?);
+    spawn_reload_watcher(Arc::clone(&live), options.watch_config);
+    let socket_mode = options.socket_mode.unwrap_or(DEFAULT_SOCKET_MODE);
+    let listener = Listener::bind(&options.listen, socket_mode)?;
+
+    info!(
+        "Serving {} checks on {} (interval {:?})",
+        live.monitor().plan().len(),
+        options.listen,
+        options.interval
+    );
+
+    if auth.is_open() && !listener.is_local() {
+        warn!("Check details are visible to any client; consider --token-file or --public");
+    }
+
+    let app = Arc::new(App {
+        live,
+        auth,
+        draining: AtomicBool::new(false),
+        active: AtomicUsize::new(0),
+        streams: AtomicUsize::new(0),
+    });
+    let socket = match &options.listen {
+        Listen::Unix(path) => Some(path.clone()),
+        Listen::Tcp(_) => None,
+    };
+    spawn_shutdown_watcher(Arc::clone(&app), options.drain, socket);
+    spawn_notifier(Arc::clone(&app.live));
+
+    loop {
+        let mut conn = match listener.accept() {
+            Ok(conn) => conn,
+            Err(e) => {
+                warn!("Failed to accept connection: {e}");
+                continue;
+            }
+        };
+
+        if app.active.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            app.active.fetch_sub(1, Ordering::SeqCst);
+            let busy = Response::error(503, "too many connections");
+            let _ = http::write_response(&mut conn, &busy, false);
+            continue;
+        }
+
+        let app = Arc::clone(&app);
+        thread::spawn(move || {
+            handle_connection(&mut conn, &app);
+            app.active.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+}
+
+fn handle_connection(conn: &mut Conn, app: &App) {
+    conn.set_timeouts(IO_TIMEOUT);
+
+    let (response, head_only) = match http::read_request(&mut *conn) {
+        Ok(request) => {
+            let response = match app.auth.check(conn.peer_ip(), &request) {
+                Ok(access) if request.path == "/events" => {
+                    return events::stream_events(conn, &request, app, access);
+                }
+                Ok(access) => routes::route(&request, app, access),
+                Err(refused) => refused,
+            };
+            debug!("{} {} -> {}", request.method, request.path, response.status);
+            (response, request.method == "HEAD")
+        }
+        Err(e) => {
+            debug!("Rejecting malformed request: {e}");
+            (Response::error(400, "bad request"), false)
+        }
+    };
+
+    if let Err(e) = http::write_response(conn, &response, head_only) {
+        debug!("Failed to write response: {e}");
+    }
+}
@@ -0,0 +1,140 @@
+//! systemd readiness and watchdog notifications (`sd_notify`)
+//!
+//! When started with `NOTIFY_SOCKET`, the server reports `READY=1` once
+//! every check has a first result, keeps `STATUS=` in step with the failing
+//! checks and, if the unit sets `WatchdogSec=`, pings `WATCHDOG=1` only while
+//! no critical check fails so systemd restarts a service that stays down.
+//! The protocol is one datagram per message, so no libsystemd is needed.
+
+use std::env;
+use std::os::unix::net::UnixDatagram;
+use std::process;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, warn};
+
+use crate::monitor::{Live, Snapshot};
+use crate::status::{OverallStatus, ReportEntry};
+
+/// Longest time between two looks at the checks
+const NOTIFY_POLL: Duration = Duration::from_secs(1);
+
+/// Datagram socket of the service manager
+pub struct Notifier {
+    socket: UnixDatagram,
+    address: String,
+}
+
+impl Notifier {
+    /// Notifier for `$NOTIFY_SOCKET`; `None` when not run by systemd
+    pub fn from_env() -> Option<Self> {
+        let address = env::var("NOTIFY_SOCKET").ok().filter(|a| !a.is_empty())?;
+        match UnixDatagram::unbound() {
+            Ok(socket) => Some(Self { socket, address }),
+            Err(e) => {
+                warn!("Cannot notify systemd: {e}");
+                None
+            }
+        }
+    }
+
+    /// Send one newline-separated `KEY=value` message
+    pub fn send(&self, message: &str) {
+        if let Err(e) = send_to(&self.socket, &self.address, message.as_bytes()) {
+            debug!("Failed to notify {}: {e}", self.address);
+        }
+    }
+}
+
+/// Addresses starting with `@` are in the abstract namespace
+#[cfg(target_os = "linux")]
+fn send_to(socket: &UnixDatagram, address: &str, message: &[u8]) -> std::io::Result<usize> {
+    use std::os::linux::net::SocketAddrExt;
+    use std::os::unix::net::SocketAddr;
+
+    match address.strip_prefix('@') {
+        Some(name) => socket.send_to_addr(message, &SocketAddr::from_abstract_name(name)?),
+        None => socket.send_to(message, address),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn send_to(socket: &UnixDatagram, address: &str, message: &[u8]) -> std::io::Result<usize> {
+    socket.send_to(message, address)
+}
+
+/// Notify systemd about `live`, if it is listening
+pub fn spawn_notifier(live: Arc<Live>) {
+    let Some(notifier) = Notifier::from_env() else {
+        return;
+    };
+    let watchdog = watchdog_interval(
+        env::var("WATCHDOG_USEC").ok().as_deref(),
+        env::var("WATCHDOG_PID").ok().as_deref(),
+        process::id(),
+    );
+    let poll = watchdog.map_or(NOTIFY_POLL, |ping| ping.min(NOTIFY_POLL));
+
+    thread::spawn(move || {
+        let mut ready = false;
+        let mut last_status = String::new();
+        loop {
+            let monitor = live.monitor();
+            if !ready
+                && monitor.with_states(|states| states.iter().all(|state| state.latest.is_some()))
+            {
+                ready = true;
+                notifier.send("READY=1");
+            }
+            if ready {
+                let snapshot = monitor.snapshot();
+                let status = status_line(&snapshot);
+                if status != last_status {
+                    notifier.send(&format!("STATUS={status}"));
+                    last_status = status;
+                }
+                if watchdog.is_some() && snapshot.status != OverallStatus::Unhealthy {
+                    notifier.send("WATCHDOG=1");
+                }
+            }
+            thread::sleep(poll);
+        }
+    });
+}
+
+/// Time between watchdog pings: half the timeout systemd gave this process
+pub fn watchdog_interval(usec: Option<&str>, pid: Option<&str>, own_pid: u32) -> Option<Duration> {
+    if pid.is_some_and(|pid| pid.parse() != Ok(own_pid)) {
+        return None;
+    }
+    let usec: u64 = usec?.parse().ok().filter(|&usec| usec > 0)?;
+    Some(Duration::from_micros(usec / 2))
+}
+
+/// One-line summary for `systemctl status`
+pub fn status_line(snapshot: &Snapshot) -> String {
+    let failing: Vec<&str> = snapshot
+        .entries
+        .iter()
+        .filter_map(|entry| match entry {
+            ReportEntry::Check(r) if !r.ok && r.skipped.is_none() => Some(r.name.as_str()),
+            ReportEntry::Group(g) if !g.ok() => Some(g.name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    let total = snapshot.entries.len();
+    match failing.len() {
+        0 => format!("{}: all {total} checks passing", snapshot.status.as_str()),
+        n => format!(
+            "{}: {n} of {total} checks failing: {}",
+            snapshot.status.as_str(),
+            failing.join(", ")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,92 @@
+use super::*;
+use crate::runner::test_support::check_result;
+use crate::status::{CheckResult, Severity};
+
+fn check(name: &str, ok: bool, severity: Severity) -> ReportEntry {
+    ReportEntry::Check(CheckResult {
+        name: name.to_string(),
+        severity,
+        ..check_result(ok)
+    })
+}
+
+fn snapshot(entries: Vec<ReportEntry>) -> Snapshot {
+    let status = OverallStatus::from_entries(&entries);
+    Snapshot { entries, status }
+}
+
+#[test]
+fn test_status_line_names_failing_checks() {
+    let all_ok = snapshot(vec![check("db", true, Severity::Critical)]);
+    assert_eq!(status_line(&all_ok), "healthy: all 1 checks passing");
+
+    let failing = snapshot(vec![
+        check("db", false, Severity::Critical),
+        check("web", true, Severity::Critical),
+        check("cache", false, Severity::Warning),
+    ]);
+    assert_eq!(
+        status_line(&failing),
+        "unhealthy: 2 of 3 checks failing: db, cache"
+    );
+}
+
+#[test]
+fn test_watchdog_interval_is_half_the_timeout() {
+    let pid = process::id();
+    let own = pid.to_string();
+    assert_eq!(
+        watchdog_interval(Some("4000000"), None, pid),
+        Some(Duration::from_secs(2))
+    );
+    assert_eq!(
+        watchdog_interval(Some("4000000"), Some(&own), pid),
+        Some(Duration::from_secs(2))
+    );
+
+    // Meant for another process, disabled or malformed
+    assert_eq!(watchdog_interval(Some("4000000"), Some("1"), pid + 1), None);
+    assert_eq!(watchdog_interval(None, None, pid), None);
+    assert_eq!(watchdog_interval(Some("0"), None, pid), None);
+    assert_eq!(watchdog_interval(Some("soon"), None, pid), None);
+}
+
+#[test]
+fn test_notifier_sends_datagrams() {
+    let mut path = env::temp_dir();
+    path.push(format!("hc_notify_unit_{}.sock", process::id()));
+    let _ = std::fs::remove_file(&path);
+    let receiver = UnixDatagram::bind(&path).unwrap();
+
+    let notifier = Notifier {
+        socket: UnixDatagram::unbound().unwrap(),
+        address: path.display().to_string(),
+    };
+    notifier.send("READY=1\nSTATUS=ok");
+
+    let mut buf = [0; 64];
+    let len = receiver.recv(&mut buf).unwrap();
+    assert_eq!(&buf[..len], b"READY=1\nSTATUS=ok");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_notifier_reaches_abstract_sockets() {
+    use std::os::linux::net::SocketAddrExt;
+    use std::os::unix::net::SocketAddr;
+
+    let name = format!("hc_notify_unit_{}", process::id());
+    let addr = SocketAddr::from_abstract_name(&name).unwrap();
+    let receiver = UnixDatagram::bind_addr(&addr).unwrap();
+
+    let notifier = Notifier {
+        socket: UnixDatagram::unbound().unwrap(),
+        address: format!("@{name}"),
+    };
+    notifier.send("WATCHDOG=1");
+
+    let mut buf = [0; 64];
+    let len = receiver.recv(&mut buf).unwrap();
+    assert_eq!(&buf[..len], b"WATCHDOG=1");
+}
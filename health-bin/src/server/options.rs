@@ -0,0 +1,44 @@
+//! Options of the `serve` command
+
+use std::time::Duration;
+
+use super::auth::AuthOptions;
+use super::listener::Listen;
+use super::shutdown::DEFAULT_DRAIN;
+use crate::monitor::DEFAULT_HISTORY;
+use crate::runner::RunOptions;
+
+pub const DEFAULT_LISTEN: &str = "127.0.0.1:9000";
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    pub listen: Listen,
+    /// Permissions of a `unix:` socket, `DEFAULT_SOCKET_MODE` if unset
+    pub socket_mode: Option<u32>,
+    /// Interval for checks without `interval_ms=`
+    pub interval: Duration,
+    pub run: RunOptions,
+    pub auth: AuthOptions,
+    /// Also reload when the config file's modification time changes
+    pub watch_config: bool,
+    /// Results kept per check for `/checks/{name}/history`
+    pub history: usize,
+    /// How long to stay up but unready after SIGTERM
+    pub drain: Duration,
+}
+
+impl Default for ServeOptions {
+    fn default() -> Self {
+        Self {
+            listen: Listen::Tcp(DEFAULT_LISTEN.to_string()),
+            socket_mode: None,
+            interval: DEFAULT_INTERVAL,
+            run: RunOptions::default(),
+            auth: AuthOptions::default(),
+            watch_config: false,
+            history: DEFAULT_HISTORY,
+            drain: DEFAULT_DRAIN,
+        }
+    }
+}
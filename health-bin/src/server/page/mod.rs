@@ -0,0 +1,101 @@
+//! `/`: status page for humans
+//!
+//! Rendered on the server from the latest results, with inline styles and
+//! SVG sparklines instead of scripts or external assets, so the page works
+//! in air-gapped networks and reloads itself every few seconds.
+
+mod sparkline;
+
+use std::fmt::Write;
+
+use crate::monitor::{CheckState, Monitor, now_ms, state_name};
+use sparkline::sparkline;
+
+pub const CONTENT_TYPE: &str = "text/html; charset=utf-8";
+
+/// Seconds between automatic reloads of the page
+const REFRESH_SECS: u32 = 5;
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;margin:2em;color:#222}\
+table{border-collapse:collapse}th,td{padding:.4em .8em;text-align:left;\
+border-bottom:1px solid #ddd}th{font-weight:600}.error{font-family:monospace;\
+max-width:40em;overflow-wrap:anywhere}.ok,.healthy{color:#18794e}\
+.warning,.degraded{color:#b35c00}.failing,.unhealthy{color:#c62828}\
+.pending,.skipped{color:#777}";
+
+pub fn render_page(monitor: &Monitor) -> String {
+    let status = monitor.snapshot().status.as_str();
+    let now = now_ms();
+
+    let mut out = String::with_capacity(2048);
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(
+        out,
+        "<meta http-equiv=\"refresh\" content=\"{REFRESH_SECS}\">\n\
+         <title>healthcheck: {status}</title>\n<style>{STYLE}</style>\n</head>\n<body>"
+    );
+    let _ = writeln!(
+        out,
+        "<h1>Status: <span class=\"{status}\">{status}</span></h1>"
+    );
+    out.push_str("<table>\n<tr><th>Check</th><th>Type</th><th>State</th><th>Latency</th>");
+    out.push_str("<th>Recent latency</th><th>Since</th><th>Last error</th></tr>\n");
+
+    for (check, state) in monitor.plan().iter().zip(monitor.states()) {
+        render_row(&mut out, &check.name, &check.check_type, &state, now);
+    }
+
+    out.push_str("</table>\n</body>\n</html>\n");
+    out
+}
+
+fn render_row(out: &mut String, name: &str, check_type: &str, state: &CheckState, now: u64) {
+    let latest = state.latest.as_ref();
+    let current = state_name(latest);
+    let latency = latest.map_or_else(String::new, |r| format!("{} ms", r.latency_ms));
+    let since = state
+        .changed_ms
+        .map_or_else(String::new, |at| elapsed(now.saturating_sub(at)));
+    let error = latest
+        .and_then(|r| r.error.as_deref().or(r.skipped.as_deref()))
+        .unwrap_or("");
+
+    let _ = writeln!(
+        out,
+        "<tr><td>{}</td><td>{}</td><td class=\"{current}\">{current}</td><td>{latency}</td>\
+         <td>{}</td><td>{since}</td><td class=\"error\">{}</td></tr>",
+        escape_html(name),
+        escape_html(check_type),
+        sparkline(&state.history),
+        escape_html(error),
+    );
+}
+
+/// Time since a state change, in its largest whole unit
+fn elapsed(ms: u64) -> String {
+    let secs = ms / 1000;
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests;
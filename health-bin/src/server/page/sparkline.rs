@@ -0,0 +1,55 @@
+//! Inline SVG latency sparklines
+
+use std::fmt::Write;
+
+use crate::monitor::History;
+
+const WIDTH: f64 = 120.0;
+const HEIGHT: f64 = 24.0;
+/// Keeps the line and the failure dots inside the image
+const MARGIN: f64 = 2.0;
+
+/// Latency of the completed runs in `history`, oldest first, with failed
+/// runs marked red; empty until there are two runs to connect
+pub fn sparkline(history: &History) -> String {
+    let runs: Vec<(u64, bool)> = history
+        .entries()
+        .filter(|entry| entry.skipped.is_none())
+        .map(|entry| (entry.latency_ms, entry.ok))
+        .collect();
+    if runs.len() < 2 {
+        return String::new();
+    }
+
+    let max = runs
+        .iter()
+        .map(|&(latency, _)| latency)
+        .max()
+        .unwrap_or(0)
+        .max(1) as f64;
+    let step = (WIDTH - 2.0 * MARGIN) / (runs.len() - 1) as f64;
+    let point = |i: usize, latency: u64| {
+        let x = MARGIN + i as f64 * step;
+        let y = HEIGHT - MARGIN - latency as f64 / max * (HEIGHT - 2.0 * MARGIN);
+        (x, y)
+    };
+
+    let mut points = String::new();
+    let mut failures = String::new();
+    for (i, &(latency, ok)) in runs.iter().enumerate() {
+        let (x, y) = point(i, latency);
+        let _ = write!(points, "{x:.1},{y:.1} ");
+        if !ok {
+            let _ = write!(failures, "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"2\"/>");
+        }
+    }
+
+    format!(
+        "<svg width=\"{WIDTH}\" height=\"{HEIGHT}\" viewBox=\"0 0 {WIDTH} {HEIGHT}\" \
+         role=\"img\" aria-label=\"latency of the last {} runs, up to {max} ms\">\
+         <polyline fill=\"none\" stroke=\"#4a6fa5\" stroke-width=\"1.5\" points=\"{}\"/>\
+         <g fill=\"#c62828\">{failures}</g></svg>",
+        runs.len(),
+        points.trim_end(),
+    )
+}
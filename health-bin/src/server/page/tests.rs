@@ -0,0 +1,61 @@
+use super::*;
+use crate::runner::test_support::check_result;
+use crate::status::CheckResult;
+
+fn result(ok: bool, latency_ms: u64) -> CheckResult {
+    CheckResult {
+        latency_ms,
+        error: (!ok).then(|| "refused <port>".to_string()),
+        ..check_result(ok)
+    }
+}
+
+#[test]
+fn test_escape_html() {
+    assert_eq!(
+        escape_html("<a href=\"x\">Tom & Jerry's</a>"),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+    );
+}
+
+#[test]
+fn test_elapsed_uses_the_largest_unit() {
+    assert_eq!(elapsed(999), "0s");
+    assert_eq!(elapsed(59_000), "59s");
+    assert_eq!(elapsed(150_000), "2m");
+    assert_eq!(elapsed(7_200_000), "2h");
+    assert_eq!(elapsed(3 * 86_400_000), "3d");
+}
+
+#[test]
+fn test_sparkline_marks_failed_runs() {
+    let mut state = CheckState::with_history(10);
+    state.record(result(true, 10));
+    assert_eq!(sparkline(&state.history), "");
+
+    state.record(result(false, 40));
+    state.record(result(true, 20));
+    let svg = sparkline(&state.history);
+    assert!(svg.starts_with("<svg"));
+    assert!(
+        svg.contains("points=\"2.0,17.0 60.0,2.0 118.0,12.0\""),
+        "{svg}"
+    );
+    assert_eq!(svg.matches("<circle").count(), 1);
+    assert!(svg.contains("up to 40 ms"));
+}
+
+#[test]
+fn test_render_row_escapes_check_details() {
+    let mut state = CheckState::with_history(10);
+    state.record(result(false, 5));
+    let now = state.changed_ms.unwrap() + 90_000;
+
+    let mut out = String::new();
+    render_row(&mut out, "<db>", "tcp", &state, now);
+    assert!(out.contains("<td>&lt;db&gt;</td>"), "{out}");
+    assert!(out.contains("<td class=\"failing\">failing</td>"));
+    assert!(out.contains("<td>5 ms</td>"));
+    assert!(out.contains("<td>1m</td>"));
+    assert!(out.contains("refused &lt;port&gt;"));
+}
@@ -0,0 +1,114 @@
+//! Endpoint dispatch
+
+use std::sync::atomic::Ordering;
+
+use super::App;
+use super::auth::Access;
+use super::http::{Request, Response};
+use super::{history, metrics, page};
+use crate::monitor::{Monitor, Snapshot};
+use crate::plan::ProbeClass;
+use crate::status::{OverallStatus, ReportEntry, render_results, render_status_only};
+
+pub fn route(request: &Request, app: &App, access: Access) -> Response {
+    let monitor = &app.live.monitor();
+    let probe = |class| status_response(monitor.probe_snapshot(class), access);
+
+    match request.path.as_str() {
+        "/" => read_only(request, || {
+            details(app, access, || {
+                Response::new(200, page::CONTENT_TYPE, page::render_page(monitor))
+            })
+        }),
+        "/health" => read_only(request, || {
+            unless_draining(app, || status_response(monitor.snapshot(), access))
+        }),
+        "/livez" => read_only(request, || probe(ProbeClass::Liveness)),
+        "/readyz" => read_only(request, || {
+            unless_draining(app, || probe(ProbeClass::Readiness))
+        }),
+        "/startupz" => read_only(request, || probe(ProbeClass::Startup)),
+        "/metrics" => read_only(request, || {
+            details(app, access, || {
+                Response::new(200, metrics::CONTENT_TYPE, metrics::render_metrics(monitor))
+            })
+        }),
+        "/checks/run" => post_only(request, || {
+            details(app, access, || {
+                status_response(monitor.run_all_now(), Access::Full)
+            })
+        }),
+        path => match (check_path(path, "/history"), check_path(path, "/run")) {
+            (Some(name), _) => read_only(request, || {
+                details(app, access, || history_response(monitor, name))
+            }),
+            (_, Some(name)) => post_only(request, || {
+                details(app, access, || run_response(monitor, name))
+            }),
+            _ => Response::error(404, "not found"),
+        },
+    }
+}
+
+/// `name` of a `/checks/{name}{suffix}` path
+fn check_path<'a>(path: &'a str, suffix: &str) -> Option<&'a str> {
+    let name = path.strip_prefix("/checks/")?.strip_suffix(suffix)?;
+    (!name.is_empty()).then_some(name)
+}
+
+/// Readiness fails as soon as shutdown starts, whatever the checks say
+fn unless_draining(app: &App, handler: impl FnOnce() -> Response) -> Response {
+    if app.draining.load(Ordering::SeqCst) {
+        return Response::error(503, "shutting down");
+    }
+    handler()
+}
+
+/// Endpoints exposing per-check details need full access
+fn details(app: &App, access: Access, handler: impl FnOnce() -> Response) -> Response {
+    match access {
+        Access::Full => handler(),
+        Access::StatusOnly => app.auth.unauthorized(),
+    }
+}
+
+fn history_response(monitor: &Monitor, name: &str) -> Response {
+    match monitor.state(name) {
+        Some(state) => Response::json(200, history::render_history(name, &state.history)),
+        None => Response::error(404, "unknown check"),
+    }
+}
+
+/// Fresh result of one check, in the same document as `/health`
+fn run_response(monitor: &Monitor, name: &str) -> Response {
+    let Some(idx) = monitor.plan().iter().position(|check| check.name == name) else {
+        return Response::error(404, "unknown check");
+    };
+    let entries = vec![ReportEntry::Check(monitor.run_now(idx))];
+    let status = OverallStatus::from_entries(&entries);
+    status_response(Snapshot { entries, status }, Access::Full)
+}
+
+/// Same document as a one-shot run; 503 when the overall status is down
+fn status_response(snapshot: Snapshot, access: Access) -> Response {
+    let status = if snapshot.status.is_up() { 200 } else { 503 };
+    let body = match access {
+        Access::Full => render_results(&snapshot.entries, snapshot.status),
+        Access::StatusOnly => render_status_only(snapshot.status),
+    };
+    Response::json(status, body)
+}
+
+fn post_only(request: &Request, handler: impl FnOnce() -> Response) -> Response {
+    match request.method.as_str() {
+        "POST" => handler(),
+        _ => Response::error(405, "method not allowed").with_header("Allow", "POST"),
+    }
+}
+
+fn read_only(request: &Request, handler: impl FnOnce() -> Response) -> Response {
+    match request.method.as_str() {
+        "GET" | "HEAD" => handler(),
+        _ => Response::error(405, "method not allowed").with_header("Allow", "GET, HEAD"),
+    }
+}
@@ -0,0 +1,66 @@
+//! Graceful shutdown on SIGTERM or SIGINT
+//!
+//! Readiness flips to 503 at once so load balancers stop sending traffic,
+//! while liveness stays green for the drain period. Then the checks stop,
+//! runs and requests in flight finish and the process exits with 0.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+
+use super::App;
+use super::notify::Notifier;
+use crate::signals::{self, Signal};
+
+/// Drain period without `--drain`
+pub const DEFAULT_DRAIN: Duration = Duration::from_secs(5);
+
+const SIGNAL_POLL: Duration = Duration::from_millis(100);
+
+/// Longest to wait for open requests once the checks have stopped
+const REQUEST_GRACE: Duration = Duration::from_secs(10);
+
+/// Watch for SIGTERM/SIGINT and shut `app` down; `socket` is removed on exit
+pub fn spawn_shutdown_watcher(app: Arc<App>, drain: Duration, socket: Option<PathBuf>) {
+    signals::install(Signal::Terminate);
+
+    thread::spawn(move || {
+        while !signals::take(Signal::Terminate) {
+            thread::sleep(SIGNAL_POLL);
+        }
+
+        app.draining.store(true, Ordering::SeqCst);
+        if let Some(notifier) = Notifier::from_env() {
+            notifier.send("STOPPING=1");
+        }
+        info!("Shutting down: unready, draining for {drain:?}");
+        thread::sleep(drain);
+
+        app.live.stop();
+        app.live.monitor().events().close();
+        wait_for_requests(&app);
+
+        if let Some(socket) = socket {
+            let _ = fs::remove_file(socket);
+        }
+        info!("Shut down");
+        process::exit(0);
+    });
+}
+
+fn wait_for_requests(app: &App) {
+    let start = Instant::now();
+    while app.active.load(Ordering::SeqCst) + app.streams.load(Ordering::SeqCst) > 0 {
+        if start.elapsed() > REQUEST_GRACE {
+            warn!("Exiting with requests still open");
+            return;
+        }
+        thread::sleep(SIGNAL_POLL);
+    }
+}
@@ -0,0 +1,66 @@
+//! Unix signal flags for long-running modes
+//!
+//! The handler only sets an atomic flag; threads that care about a signal
+//! poll it with `take`, so no real work happens in signal context.
+
+use std::os::raw::c_int;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// SIGHUP: reload the config
+    Hangup,
+    /// SIGTERM or SIGINT: shut down
+    Terminate,
+}
+
+const SIGHUP: c_int = 1;
+const SIGINT: c_int = 2;
+const SIGTERM: c_int = 15;
+
+static HANGUP: AtomicBool = AtomicBool::new(false);
+static TERMINATE: AtomicBool = AtomicBool::new(false);
+
+unsafe extern "C" {
+    fn signal(signum: c_int, handler: usize) -> usize;
+}
+
+extern "C" fn on_signal(signum: c_int) {
+    match signum {
+        SIGHUP => HANGUP.store(true, Ordering::SeqCst),
+        SIGINT | SIGTERM => TERMINATE.store(true, Ordering::SeqCst),
+        _ => {}
+    }
+}
+
+impl Signal {
+    fn numbers(self) -> &'static [c_int] {
+        match self {
+            Self::Hangup => &[SIGHUP],
+            Self::Terminate => &[SIGTERM, SIGINT],
+        }
+    }
+
+    fn flag(self) -> &'static AtomicBool {
+        match self {
+            Self::Hangup => &HANGUP,
+            Self::Terminate => &TERMINATE,
+        }
+    }
+}
+
+/// Record `sig` instead of taking its default action (which exits)
+pub fn install(sig: Signal) {
+    let handler = on_signal as extern "C" fn(c_int) as usize;
+    for &signum in sig.numbers() {
+        // SAFETY: the handler only stores to an atomic, which is async-signal-safe
+        unsafe {
+            signal(signum, handler);
+        }
+    }
+}
+
+/// Whether `sig` arrived since the last call; clears the flag
+pub fn take(sig: Signal) -> bool {
+    sig.flag().swap(false, Ordering::SeqCst)
+}
@@ -0,0 +1,41 @@
+//! RFC 8259 string escaping for the hand-written JSON output
+
+/// Render a list of strings as a single-line JSON array
+pub fn json_string_array(items: &[String]) -> String {
+    let quoted: Vec<String> = items
+        .iter()
+        .map(|item| format!("\"{}\"", escape_json_string(item)))
+        .collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+// Extra capacity for escape sequences in JSON strings
+const JSON_ESCAPE_BUFFER: usize = 16;
+
+/// Escape special characters in JSON strings per RFC 8259
+///
+/// This function is public for testing purposes
+pub fn escape_json_string(s: &str) -> String {
+    // Pre-allocate with some extra capacity for escape sequences
+    let mut result = String::with_capacity(s.len() + JSON_ESCAPE_BUFFER);
+
+    for ch in s.chars() {
+        match ch {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '/' => result.push_str("\\/"), // Solidus (optional but recommended for XSS prevention)
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{0008}' => result.push_str("\\b"), // backspace
+            '\u{000C}' => result.push_str("\\f"), // form feed
+            // Escape all other control characters (U+0000 to U+001F)
+            c if c < '\u{0020}' => {
+                result.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            _ => result.push(ch),
+        }
+    }
+
+    result
+}
@@ -7,81 +7,84 @@
 // The current implementation is RFC 8259 compliant with comprehensive test coverage (10 tests).
 // If JSON structure becomes more complex in the future, consider switching to serde_json.
 
-#[derive(Debug)]
+mod json;
+mod overall;
+mod render;
+
+pub use json::escape_json_string;
+pub use overall::{ExitCodes, OverallStatus, Severity};
+pub use render::{render_results, render_status_only};
+
+#[derive(Debug, Clone)]
 pub struct CheckResult {
+    pub name: String,
     pub check_type: String,
+    pub target: String,
+    pub severity: Severity,
     pub ok: bool,
     pub latency_ms: u64,
     pub error: Option<String>,
+    pub attempts: u32,
+    pub attempt_errors: Vec<String>,
+    /// Why the check was not run, e.g. "dependency db failed"
+    pub skipped: Option<String>,
+    /// Set when the check passed but slower than `warn_latency_ms`
+    pub warning: Option<String>,
+    /// Run counters behind `rise=`/`fall=`; long-running modes only
+    pub streak: Option<Streak>,
 }
 
-pub fn print_results(results: &[CheckResult], overall: bool) {
-    println!("{{");
-    println!("  \"overall\": {},", if overall { "true" } else { "false" });
-    println!("  \"checks\": [");
+/// Consecutive passing and failing runs of a check, with the number of
+/// each needed to change its state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    pub successes: u64,
+    pub failures: u64,
+    pub rise: u32,
+    pub fall: u32,
+}
 
-    for (i, result) in results.iter().enumerate() {
-        let comma = if i < results.len() - 1 { "," } else { "" };
-        println!("    {{");
-        println!("      \"type\": \"{}\",", result.check_type);
-        println!(
-            "      \"ok\": {},",
-            if result.ok { "true" } else { "false" }
-        );
-        println!("      \"latency_ms\": {},", result.latency_ms);
+/// Quorum group: passes when at least `min_ok` members pass
+#[derive(Debug)]
+pub struct GroupResult {
+    pub name: String,
+    pub severity: Severity,
+    pub min_ok: usize,
+    pub members: Vec<CheckResult>,
+}
 
-        if let Some(err) = &result.error {
-            let escaped = escape_json_string(err);
-            println!("      \"error\": \"{}\"", escaped);
-        } else {
-            println!("      \"error\": null");
-        }
+impl GroupResult {
+    pub fn ok_count(&self) -> usize {
+        self.members.iter().filter(|m| m.ok).count()
+    }
 
-        println!("    }}{}", comma);
+    pub fn ok(&self) -> bool {
+        self.ok_count() >= self.min_ok
     }
+}
 
-    println!("  ]");
-    println!("}}");
+/// Top-level item of the `checks` array
+#[derive(Debug)]
+pub enum ReportEntry {
+    Check(CheckResult),
+    Group(GroupResult),
+}
+
+pub fn print_results(entries: &[ReportEntry], status: OverallStatus) {
+    print!("{}", render_results(entries, status));
 }
 
 pub fn print_error_json(message: &str) {
-    let escaped = escape_json_string(message);
+    let escaped = json::escape_json_string(message);
     println!("{{");
     println!("  \"overall\": false,");
+    println!("  \"status\": \"unhealthy\",");
     println!("  \"error\": \"{}\"", escaped);
     println!("}}");
 }
 
-// Extra capacity for escape sequences in JSON strings
-const JSON_ESCAPE_BUFFER: usize = 16;
-
-/// Escape special characters in JSON strings per RFC 8259
-///
-/// This function is public for testing purposes
-fn escape_json_string(s: &str) -> String {
-    // Pre-allocate with some extra capacity for escape sequences
-    let mut result = String::with_capacity(s.len() + JSON_ESCAPE_BUFFER);
-
-    for ch in s.chars() {
-        match ch {
-            '\\' => result.push_str("\\\\"),
-            '"' => result.push_str("\\\""),
-            '/' => result.push_str("\\/"), // Solidus (optional but recommended for XSS prevention)
-            '\n' => result.push_str("\\n"),
-            '\r' => result.push_str("\\r"),
-            '\t' => result.push_str("\\t"),
-            '\u{0008}' => result.push_str("\\b"), // backspace
-            '\u{000C}' => result.push_str("\\f"), // form feed
-            // Escape all other control characters (U+0000 to U+001F)
-            c if c < '\u{0020}' => {
-                result.push_str(&format!("\\u{:04x}", c as u32));
-            }
-            _ => result.push(ch),
-        }
-    }
-
-    result
-}
-
 #[cfg(test)]
 mod tests;
+
+#[cfg(test)]
+mod overall_tests;
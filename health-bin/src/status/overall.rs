@@ -0,0 +1,132 @@
+//! Check severities and the three-state overall status
+
+use std::collections::HashMap;
+
+use super::ReportEntry;
+
+/// How much a failing check matters to the overall status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Severity {
+    /// Failure makes the service unhealthy
+    #[default]
+    Critical,
+    /// Failure only degrades the service
+    Warning,
+}
+
+impl Severity {
+    pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        match params.get("severity").map(String::as_str) {
+            None | Some("critical") => Ok(Self::Critical),
+            Some("warning") => Ok(Self::Warning),
+            Some(other) => Err(format!(
+                "severity must be 'critical' or 'warning', got '{other}'"
+            )),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+/// Aggregate state of all checks, ordered from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OverallStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+impl OverallStatus {
+    /// Unhealthy if a critical check or group failed, degraded if only
+    /// warning checks failed or a passing check exceeded `warn_latency_ms`
+    ///
+    /// Skipped checks are ignored: the failed dependency that caused the
+    /// skip already counts. Group members only count through their group.
+    pub fn from_entries(entries: &[ReportEntry]) -> Self {
+        entries
+            .iter()
+            .filter_map(|entry| match entry {
+                ReportEntry::Check(r) if !r.ok && r.skipped.is_none() => Some(r.severity),
+                ReportEntry::Check(r) if r.warning.is_some() => Some(Severity::Warning),
+                ReportEntry::Group(g) if !g.ok() => Some(g.severity),
+                ReportEntry::Group(g) if g.members.iter().any(|m| m.warning.is_some()) => {
+                    Some(Severity::Warning)
+                }
+                _ => None,
+            })
+            .map(|severity| match severity {
+                Severity::Critical => Self::Unhealthy,
+                Severity::Warning => Self::Degraded,
+            })
+            .max()
+            .unwrap_or(Self::Healthy)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+            Self::Unhealthy => "unhealthy",
+        }
+    }
+
+    /// Inverse of `as_str`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "healthy" => Some(Self::Healthy),
+            "degraded" => Some(Self::Degraded),
+            "unhealthy" => Some(Self::Unhealthy),
+            _ => None,
+        }
+    }
+
+    /// Whether the service should be considered up (healthy or degraded)
+    pub fn is_up(self) -> bool {
+        self != Self::Unhealthy
+    }
+}
+
+/// Mapping from overall status to process exit code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExitCodes {
+    /// Docker-compatible: 0 healthy or degraded, 1 unhealthy, 2 config error
+    #[default]
+    Docker,
+    /// Nagios plugin convention: 0 OK, 1 WARNING, 2 CRITICAL, 3 UNKNOWN
+    Nagios,
+}
+
+impl ExitCodes {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "docker" => Ok(Self::Docker),
+            "nagios" => Ok(Self::Nagios),
+            other => Err(format!(
+                "exit codes must be 'docker' or 'nagios', got '{other}'"
+            )),
+        }
+    }
+
+    pub fn for_status(self, status: OverallStatus) -> i32 {
+        match (self, status) {
+            (_, OverallStatus::Healthy) => 0,
+            (Self::Docker, OverallStatus::Degraded) => 0,
+            (Self::Docker, OverallStatus::Unhealthy) => 1,
+            (Self::Nagios, OverallStatus::Degraded) => 1,
+            (Self::Nagios, OverallStatus::Unhealthy) => 2,
+        }
+    }
+
+    /// Exit code when the config cannot be loaded at all
+    pub fn config_error(self) -> i32 {
+        match self {
+            Self::Docker => 2,
+            Self::Nagios => 3,
+        }
+    }
+}
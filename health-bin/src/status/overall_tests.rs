@@ -0,0 +1,123 @@
+use super::*;
+use crate::runner::test_support::check_result;
+
+fn result(ok: bool, severity: Severity) -> CheckResult {
+    CheckResult {
+        severity,
+        ..check_result(ok)
+    }
+}
+
+fn check(ok: bool, severity: Severity) -> ReportEntry {
+    ReportEntry::Check(result(ok, severity))
+}
+
+fn group(min_ok: usize, members: &[bool]) -> ReportEntry {
+    ReportEntry::Group(GroupResult {
+        name: "api".to_string(),
+        severity: Severity::Critical,
+        min_ok,
+        members: members
+            .iter()
+            .map(|&ok| result(ok, Severity::Critical))
+            .collect(),
+    })
+}
+
+#[test]
+fn test_overall_status_all_passing_is_healthy() {
+    let results = vec![
+        check(true, Severity::Critical),
+        check(true, Severity::Warning),
+    ];
+    assert_eq!(
+        OverallStatus::from_entries(&results),
+        OverallStatus::Healthy
+    );
+}
+
+#[test]
+fn test_overall_status_warning_failure_is_degraded() {
+    let results = vec![
+        check(true, Severity::Critical),
+        check(false, Severity::Warning),
+    ];
+    let status = OverallStatus::from_entries(&results);
+    assert_eq!(status, OverallStatus::Degraded);
+    assert!(status.is_up());
+}
+
+#[test]
+fn test_overall_status_critical_failure_wins() {
+    let results = vec![
+        check(false, Severity::Warning),
+        check(false, Severity::Critical),
+    ];
+    assert_eq!(
+        OverallStatus::from_entries(&results),
+        OverallStatus::Unhealthy
+    );
+}
+
+#[test]
+fn test_latency_warning_is_degraded() {
+    let mut slow = result(true, Severity::Critical);
+    slow.warning = Some("latency 120ms exceeded warn_latency_ms=100".to_string());
+    assert_eq!(
+        OverallStatus::from_entries(&[ReportEntry::Check(slow)]),
+        OverallStatus::Degraded
+    );
+}
+
+#[test]
+fn test_group_meeting_quorum_is_healthy() {
+    let results = vec![group(2, &[true, false, true])];
+    assert_eq!(
+        OverallStatus::from_entries(&results),
+        OverallStatus::Healthy
+    );
+}
+
+#[test]
+fn test_group_below_quorum_uses_group_severity() {
+    let results = vec![check(true, Severity::Critical), group(2, &[true, false])];
+    assert_eq!(
+        OverallStatus::from_entries(&results),
+        OverallStatus::Unhealthy
+    );
+}
+
+#[test]
+fn test_render_nests_group_members() {
+    let json = render_results(&[group(1, &[false, true])], OverallStatus::Healthy);
+    assert!(json.contains("\"group\": \"api\""));
+    assert!(json.contains("\"ok_count\": 1"));
+    assert!(json.contains("\"members\": [\n        {"));
+}
+
+#[test]
+fn test_exit_code_mappings() {
+    use OverallStatus::*;
+    let docker: Vec<i32> = [Healthy, Degraded, Unhealthy]
+        .map(|s| ExitCodes::Docker.for_status(s))
+        .to_vec();
+    let nagios: Vec<i32> = [Healthy, Degraded, Unhealthy]
+        .map(|s| ExitCodes::Nagios.for_status(s))
+        .to_vec();
+    assert_eq!(docker, vec![0, 0, 1]);
+    assert_eq!(nagios, vec![0, 1, 2]);
+    assert_eq!(ExitCodes::Nagios.config_error(), 3);
+    assert!(ExitCodes::parse("systemd").is_err());
+}
+
+#[test]
+fn test_severity_from_params() {
+    let mut params = std::collections::HashMap::new();
+    assert_eq!(Severity::from_params(&params), Ok(Severity::Critical));
+
+    params.insert("severity".to_string(), "warning".to_string());
+    assert_eq!(Severity::from_params(&params), Ok(Severity::Warning));
+
+    params.insert("severity".to_string(), "fatal".to_string());
+    assert!(Severity::from_params(&params).is_err());
+}
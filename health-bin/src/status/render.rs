@@ -0,0 +1,99 @@
+//! JSON rendering of run results
+
+use std::fmt::Write;
+
+use super::json::{escape_json_string, json_string_array};
+use super::{CheckResult, GroupResult, OverallStatus, ReportEntry};
+
+/// Render the full status document
+pub fn render_results(entries: &[ReportEntry], status: OverallStatus) -> String {
+    let mut out = String::with_capacity(256 * entries.len().max(1));
+    out.push_str("{\n");
+    let _ = writeln!(out, "  \"overall\": {},", status.is_up());
+    let _ = writeln!(out, "  \"status\": \"{}\",", status.as_str());
+    out.push_str("  \"checks\": [\n");
+
+    for (i, entry) in entries.iter().enumerate() {
+        let comma = if i + 1 < entries.len() { "," } else { "" };
+        match entry {
+            ReportEntry::Check(result) => render_check(&mut out, result, "    ", comma),
+            ReportEntry::Group(group) => render_group(&mut out, group, comma),
+        }
+    }
+
+    out.push_str("  ]\n");
+    out.push_str("}\n");
+    out
+}
+
+/// Overall status without any per-check details
+pub fn render_status_only(status: OverallStatus) -> String {
+    format!(
+        "{{\n  \"overall\": {},\n  \"status\": \"{}\"\n}}\n",
+        status.is_up(),
+        status.as_str()
+    )
+}
+
+fn render_group(out: &mut String, group: &GroupResult, comma: &str) {
+    out.push_str("    {\n");
+    let _ = writeln!(
+        out,
+        "      \"group\": \"{}\",",
+        escape_json_string(&group.name)
+    );
+    let _ = writeln!(out, "      \"severity\": \"{}\",", group.severity.as_str());
+    let _ = writeln!(out, "      \"ok\": {},", group.ok());
+    let _ = writeln!(out, "      \"min_ok\": {},", group.min_ok);
+    let _ = writeln!(out, "      \"ok_count\": {},", group.ok_count());
+    out.push_str("      \"members\": [\n");
+
+    for (i, member) in group.members.iter().enumerate() {
+        let member_comma = if i + 1 < group.members.len() { "," } else { "" };
+        render_check(out, member, "        ", member_comma);
+    }
+
+    out.push_str("      ]\n");
+    let _ = writeln!(out, "    }}{comma}");
+}
+
+fn render_check(out: &mut String, result: &CheckResult, indent: &str, comma: &str) {
+    let _ = writeln!(out, "{indent}{{");
+    let field = |out: &mut String, key: &str, value: &str| {
+        let _ = writeln!(out, "{indent}  \"{key}\": {value},");
+    };
+
+    field(out, "name", &quoted(&result.name));
+    field(out, "type", &quoted(&result.check_type));
+    field(out, "target", &quoted(&result.target));
+    field(out, "severity", &quoted(result.severity.as_str()));
+    field(out, "ok", &result.ok.to_string());
+    field(out, "latency_ms", &result.latency_ms.to_string());
+    field(out, "attempts", &result.attempts.to_string());
+    field(
+        out,
+        "attempt_errors",
+        &json_string_array(&result.attempt_errors),
+    );
+    field(out, "skipped", &optional(result.skipped.as_deref()));
+    field(out, "warning", &optional(result.warning.as_deref()));
+    if let Some(streak) = result.streak {
+        let _ = writeln!(
+            out,
+            "{indent}  \"streak\": {{\"successes\": {}, \"failures\": {}, \"rise\": {}, \"fall\": {}}},",
+            streak.successes, streak.failures, streak.rise, streak.fall
+        );
+    }
+
+    let error = optional(result.error.as_deref());
+    let _ = writeln!(out, "{indent}  \"error\": {error}");
+    let _ = writeln!(out, "{indent}}}{comma}");
+}
+
+fn quoted(value: &str) -> String {
+    format!("\"{}\"", escape_json_string(value))
+}
+
+fn optional(value: Option<&str>) -> String {
+    value.map_or_else(|| "null".to_string(), quoted)
+}
@@ -0,0 +1,87 @@
+//! `watch`: live terminal dashboard of continuously running checks
+//!
+//! Checks run on the same per-check schedules as in serve mode. On a
+//! terminal the table is redrawn in place with plain ANSI escapes; when
+//! stdout is a pipe or file, each refresh appends an uncoloured table
+//! instead, so the output stays readable in logs.
+
+mod table;
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::monitor::{Live, spawn_reload_watcher};
+use crate::runner::RunOptions;
+use table::render_table;
+
+/// Refresh interval without `--interval`
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Move the cursor home and clear the screen
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Interval for checks without `interval_ms=`, and between redraws
+    pub interval: Duration,
+    pub run: RunOptions,
+    /// Also reload when the config file's modification time changes
+    pub watch_config: bool,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_INTERVAL,
+            run: RunOptions::default(),
+            watch_config: false,
+        }
+    }
+}
+
+/// Run the checks of `config_path` and redraw their table until killed
+///
+/// SIGHUP (and with `--watch-config` a changed config file) reloads the
+/// checks, as in serve mode.
+/// Returns once stdout is closed, e.g. when piped into `head`.
+pub fn watch(config_path: &str, options: WatchOptions) -> Result<(), String> {
+    // The table only shows the latest result, so no history is kept
+    let live = Arc::new(Live::start(config_path, options.run, options.interval, 0)?);
+    spawn_reload_watcher(Arc::clone(&live), options.watch_config);
+    let terminal = io::stdout().is_terminal();
+    let started = Instant::now();
+
+    if !terminal {
+        // Appended output skips the frame of still pending checks
+        thread::sleep(options.interval);
+    }
+    loop {
+        let monitor = live.monitor();
+        let status = monitor.snapshot().status;
+        let table = render_table(monitor.plan(), &monitor.states(), status, terminal);
+        let frame = if terminal {
+            format!(
+                "{CLEAR}{config_path} - every {:?}\n\n{table}",
+                options.interval
+            )
+        } else {
+            format!("[+{}s]\n{table}\n", started.elapsed().as_secs())
+        };
+
+        let mut stdout = io::stdout().lock();
+        if stdout
+            .write_all(frame.as_bytes())
+            .and_then(|()| stdout.flush())
+            .is_err()
+        {
+            return Ok(());
+        }
+        drop(stdout);
+        thread::sleep(options.interval);
+    }
+}
+
+#[cfg(test)]
+mod tests;
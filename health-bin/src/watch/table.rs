@@ -0,0 +1,88 @@
+//! The table of check states drawn by `watch`
+
+use std::fmt::Write;
+
+use crate::monitor::{CheckState, state_name};
+use crate::plan::PlannedCheck;
+use crate::status::OverallStatus;
+
+/// Longest last error shown; the rest is cut off
+const ERROR_WIDTH: usize = 60;
+
+const HEADER: [&str; 6] = ["NAME", "TYPE", "STATE", "LATENCY", "FAILS", "LAST ERROR"];
+
+/// `status` line followed by one row per check, in plan order; states are
+/// coloured with ANSI escapes when `color` is set
+pub fn render_table(
+    plan: &[PlannedCheck],
+    states: &[CheckState],
+    status: OverallStatus,
+    color: bool,
+) -> String {
+    let rows: Vec<[String; 6]> = plan
+        .iter()
+        .zip(states)
+        .map(|(check, state)| {
+            let latest = state.latest.as_ref();
+            let error = latest.and_then(|r| r.error.as_deref().or(r.skipped.as_deref()));
+            [
+                check.name.clone(),
+                check.check_type.clone(),
+                state_name(latest).to_string(),
+                latest.map_or_else(|| "-".to_string(), |r| format!("{} ms", r.latency_ms)),
+                state.consecutive_failures.to_string(),
+                error.map_or_else(String::new, truncate),
+            ]
+        })
+        .collect();
+
+    let width = |column: usize| {
+        rows.iter()
+            .map(|row| row[column].chars().count())
+            .chain([HEADER[column].len()])
+            .max()
+            .unwrap_or(0)
+    };
+    let (name, kind, latency, fails) = (width(0), width(1), width(3), width(4));
+
+    let status = status.as_str();
+    let mut out = format!("{}\n", paint(status, &status.to_uppercase(), color));
+    let _ = writeln!(
+        out,
+        "{:name$}  {:kind$}  {:7}  {:>latency$}  {:>fails$}  {}",
+        HEADER[0], HEADER[1], HEADER[2], HEADER[3], HEADER[4], HEADER[5]
+    );
+    for [n, t, state, l, f, error] in &rows {
+        let state = paint(state, &format!("{state:7}"), color);
+        let line = format!("{n:name$}  {t:kind$}  {state}  {l:>latency$}  {f:>fails$}  {error}");
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    out
+}
+
+/// `text` in the colour of `state`
+fn paint(state: &str, text: &str, color: bool) -> String {
+    let code = match state {
+        "ok" | "healthy" => "32",
+        "warning" | "degraded" => "33",
+        "failing" | "unhealthy" => "31",
+        _ => "2",
+    };
+    if color {
+        format!("\x1b[{code}m{text}\x1b[0m")
+    } else {
+        text.to_string()
+    }
+}
+
+/// One line of at most `ERROR_WIDTH` characters
+fn truncate(error: &str) -> String {
+    let line: String = error
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    match line.char_indices().nth(ERROR_WIDTH - 1) {
+        Some((end, _)) if line.chars().count() > ERROR_WIDTH => format!("{}…", &line[..end]),
+        _ => line,
+    }
+}
@@ -0,0 +1,82 @@
+use super::table::render_table;
+use crate::monitor::CheckState;
+use crate::plan::{CheckOptions, PlannedCheck};
+use crate::runner::test_support::check_result;
+use crate::status::{CheckResult, OverallStatus};
+
+fn planned(name: &str, check_type: &str) -> PlannedCheck {
+    PlannedCheck {
+        name: name.to_string(),
+        check_type: check_type.to_string(),
+        target: String::new(),
+        line: 1,
+        options: CheckOptions::default(),
+        probe: Err("not run in these tests".to_string()),
+        deps: Vec::new(),
+    }
+}
+
+fn state(results: &[(bool, u64, Option<&str>)]) -> CheckState {
+    let mut state = CheckState::default();
+    for &(ok, latency_ms, error) in results {
+        state.record(CheckResult {
+            latency_ms,
+            error: error.map(str::to_string),
+            ..check_result(ok)
+        });
+    }
+    state
+}
+
+#[test]
+fn test_table_aligns_columns_and_counts_failure_streaks() {
+    let plan = [
+        planned("web", "http"),
+        planned("database", "tcp"),
+        planned("q", "tcp"),
+    ];
+    let states = [
+        state(&[(true, 12, None)]),
+        state(&[
+            (false, 3, Some("down")),
+            (false, 1003, Some("Connection refused")),
+        ]),
+        CheckState::default(),
+    ];
+
+    let table = render_table(&plan, &states, OverallStatus::Unhealthy, false);
+    let expected = "\
+UNHEALTHY
+NAME      TYPE  STATE    LATENCY  FAILS  LAST ERROR
+web       http  ok         12 ms      0
+database  tcp   failing  1003 ms      2  Connection refused
+q         tcp   pending        -      0
+";
+    assert_eq!(table, expected);
+}
+
+#[test]
+fn test_table_colours_states_on_terminals_only() {
+    let plan = [planned("web", "http")];
+    let states = [state(&[(false, 1, Some("line one\nline two"))])];
+
+    let colored = render_table(&plan, &states, OverallStatus::Unhealthy, true);
+    assert!(colored.contains("\x1b[31mfailing\x1b[0m"), "{colored:?}");
+    assert!(colored.starts_with("\x1b[31mUNHEALTHY\x1b[0m\n"));
+    // Errors stay on their row
+    assert!(colored.contains("line one line two"));
+
+    let plain = render_table(&plan, &states, OverallStatus::Unhealthy, false);
+    assert!(!plain.contains('\x1b'));
+}
+
+#[test]
+fn test_table_cuts_long_errors() {
+    let plan = [planned("web", "http")];
+    let long = "x".repeat(100);
+    let states = [state(&[(false, 1, Some(&long))])];
+
+    let table = render_table(&plan, &states, OverallStatus::Unhealthy, false);
+    let row = table.lines().last().unwrap();
+    assert!(row.ends_with(&format!("{}…", "x".repeat(59))), "{row}");
+}
@@ -43,33 +43,45 @@ fn test_unknown_flag_error() {
 }
 
 #[test]
-fn test_serve_command_unimplemented() {
+fn test_serve_command_fails_on_missing_config() {
     let output = Command::new(get_healthcheck_bin())
-        .arg("serve")
+        .args(["serve", "/nonexistent/path/config.conf"])
         .output()
         .expect("failed to execute healthcheck serve");
 
     assert!(!output.status.success(), "Should exit with error code");
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        stderr.contains("not yet implemented") || stderr.contains("Coming soon"),
-        "serve command should indicate it's not implemented yet. Got: {}",
+        stderr.contains("failed to read config"),
+        "serve should report the unreadable config. Got: {}",
         stderr
     );
 }
 
 #[test]
-fn test_watch_command_unimplemented() {
+fn test_serve_command_rejects_invalid_interval() {
     let output = Command::new(get_healthcheck_bin())
-        .arg("watch")
+        .args(["serve", "--interval", "soon"])
+        .output()
+        .expect("failed to execute healthcheck serve");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("invalid duration"), "Got: {}", stderr);
+}
+
+#[test]
+fn test_watch_command_reports_missing_config() {
+    let output = Command::new(get_healthcheck_bin())
+        .args(["watch", "/nonexistent/healthcheck.config"])
         .output()
         .expect("failed to execute healthcheck watch");
 
-    assert!(!output.status.success(), "Should exit with error code");
+    assert_eq!(output.status.code(), Some(1));
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        stderr.contains("not yet implemented") || stderr.contains("Coming soon"),
-        "watch command should indicate it's not implemented yet. Got: {}",
+        stderr.contains("failed to read config"),
+        "watch should report the unreadable config. Got: {}",
         stderr
     );
 }
@@ -56,3 +56,32 @@ fn healthcheck_parses_valid_config() {
         "Should output JSON with checks array"
     );
 }
+
+#[test]
+fn healthcheck_warning_failure_is_degraded() {
+    let mut config_path = env::temp_dir();
+    config_path.push("test_degraded_config.conf");
+
+    // Port 1 is never listening, so this warning-level check always fails
+    let config_content = "tcp:host=127.0.0.1,port=1,timeout_ms=200,severity=warning\n";
+    fs::write(&config_path, config_content).expect("failed to write config");
+
+    let docker = Command::new(get_healthcheck_bin())
+        .arg(&config_path)
+        .output()
+        .expect("failed to execute healthcheck");
+    let nagios = Command::new(get_healthcheck_bin())
+        .arg("--exit-codes")
+        .arg("nagios")
+        .arg(&config_path)
+        .output()
+        .expect("failed to execute healthcheck");
+
+    fs::remove_file(&config_path).ok();
+
+    let stdout = String::from_utf8_lossy(&docker.stdout);
+    assert!(stdout.contains("\"status\": \"degraded\""), "{stdout}");
+    assert!(stdout.contains("\"severity\": \"warning\""), "{stdout}");
+    assert_eq!(docker.status.code(), Some(0));
+    assert_eq!(nagios.status.code(), Some(1));
+}
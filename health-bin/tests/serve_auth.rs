@@ -0,0 +1,61 @@
+mod common;
+mod serve_support;
+
+use std::env;
+use std::fs;
+
+use serve_support::{Server, closed_port};
+
+fn token_file(name: &str) -> String {
+    let mut path = env::temp_dir();
+    path.push(format!("serve_token_{name}_{}", std::process::id()));
+    fs::write(&path, "s3cret\n").expect("failed to write token");
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn serve_requires_bearer_token() {
+    let token = token_file("required");
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("token", &config, &["--token-file", &token]);
+
+    let (status, response) = server.get("/health");
+    assert_eq!(status, 401);
+    assert!(response.contains("WWW-Authenticate: Bearer"));
+    assert!(!response.contains("\"name\""));
+
+    let (status, response) =
+        server.request_with("GET", "/health", &["Authorization: Bearer s3cret"]);
+    assert!(status == 200 || status == 503, "{response}");
+    assert!(response.contains("\"name\": \"db\""));
+
+    let _ = fs::remove_file(token);
+}
+
+#[test]
+fn serve_public_mode_returns_status_only() {
+    let token = token_file("public");
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("public", &config, &["--public", "--token-file", &token]);
+
+    let (status, response) = server.wait_for("/readyz", |_, body| body.contains("unhealthy"));
+    assert_eq!(status, 503);
+    assert!(response.contains("\"status\": \"unhealthy\""));
+    assert!(!response.contains("\"checks\""), "{response}");
+    assert_eq!(server.get("/metrics").0, 401);
+
+    let (_, response) = server.request_with("GET", "/metrics", &["Authorization: Bearer s3cret"]);
+    assert!(response.contains("healthcheck_up{name=\"db\""));
+
+    let _ = fs::remove_file(token);
+}
+
+#[test]
+fn serve_allowlist_refuses_other_sources() {
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("allow", &config, &["--allow", "10.0.0.0/8"]);
+
+    let (status, response) = server.get("/health");
+    assert_eq!(status, 403);
+    assert!(!response.contains("\"name\""));
+}
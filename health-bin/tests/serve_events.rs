@@ -0,0 +1,75 @@
+mod common;
+mod serve_support;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use serve_support::{Server, closed_port};
+
+#[test]
+fn serve_streams_replay_then_transitions() {
+    let config = format!(
+        "tcp:name=db,host=127.0.0.1,port={},initial_delay_ms=500\n",
+        closed_port()
+    );
+    let server = Server::start("events", &config, &[]);
+
+    let mut stream = TcpStream::connect(&server.addr).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    write!(stream, "GET /events HTTP/1.1\r\nHost: test\r\n\r\n").unwrap();
+
+    let mut lines = BufReader::new(stream).lines().map(|line| line.unwrap());
+    let head: Vec<String> = lines.by_ref().take_while(|l| !l.is_empty()).collect();
+    assert_eq!(head[0], "HTTP/1.1 200 OK");
+    assert!(head.contains(&"Content-Type: text/event-stream".to_string()));
+
+    let mut events = lines.filter(|line| !line.is_empty());
+    assert_eq!(events.next().unwrap(), "event: replay");
+    let replay = events.next().unwrap();
+    assert!(replay.contains("\"name\": \"db\""), "{replay}");
+    assert!(
+        replay.contains("\"old\": null, \"new\": \"pending\""),
+        "{replay}"
+    );
+
+    assert_eq!(events.next().unwrap(), "event: transition");
+    let transition = events.next().unwrap();
+    assert!(
+        transition.contains("\"old\": \"pending\", \"new\": \"failing\""),
+        "{transition}"
+    );
+    assert!(transition.contains("refused"), "{transition}");
+}
+
+#[test]
+fn serve_caps_event_streams_without_starving_probes() {
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("events_cap", &config, &[]);
+
+    let open = || {
+        let mut stream = TcpStream::connect(&server.addr).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        write!(stream, "GET /events HTTP/1.1\r\nHost: test\r\n\r\n").unwrap();
+        let mut status = String::new();
+        BufReader::new(&stream).read_line(&mut status).unwrap();
+        (stream, status)
+    };
+    let streams: Vec<_> = (0..8).map(|_| open()).collect();
+    assert!(streams.iter().all(|(_, s)| s.starts_with("HTTP/1.1 200")));
+
+    let (_, refused) = open();
+    assert!(refused.starts_with("HTTP/1.1 503"), "{refused}");
+    assert_eq!(server.get("/livez").0, 200);
+}
+
+#[test]
+fn serve_events_only_accepts_get() {
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("events_post", &config, &[]);
+    assert_eq!(server.request("POST", "/events").0, 405);
+}
@@ -0,0 +1,34 @@
+mod common;
+mod serve_support;
+
+use std::net::TcpListener;
+
+use serve_support::Server;
+
+#[test]
+fn serve_only_fails_a_check_after_fall_failures_in_a_row() {
+    let target = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = target.local_addr().unwrap().port();
+    let config = format!("tcp:name=db,host=127.0.0.1,port={port},interval_ms=100,fall=3,rise=2\n");
+    let server = Server::start("hysteresis", &config, &[]);
+
+    let (status, _) = server.wait_for("/health", |status, _| status == 200);
+    assert_eq!(status, 200);
+    drop(target);
+
+    // The first failures are absorbed: still ok, with the counter visible
+    let absorbed = |_, body: &str| (1..3).any(|n| body.contains(&format!("\"failures\": {n},")));
+    let (status, body) = server.wait_for("/health", absorbed);
+    assert_eq!(status, 200, "{body}");
+    assert!(body.contains("\"successes\": 0,") && body.contains("\"rise\": 2, \"fall\": 3}"));
+    assert!(body.contains("refused"), "{body}");
+
+    let (status, body) = server.wait_for("/health", |_, body| body.contains("\"failures\": 3,"));
+    assert_eq!(status, 503, "{body}");
+
+    let (_, metrics) = server.get("/metrics");
+    assert!(metrics.contains("healthcheck_up{name=\"db\",type=\"tcp\"} 0"));
+    assert!(metrics.contains("healthcheck_consecutive_successes{name=\"db\",type=\"tcp\"} 0"));
+    let failures = "healthcheck_consecutive_failures{name=\"db\",type=\"tcp\"} ";
+    assert!(metrics.contains(failures) && !metrics.contains(&format!("{failures}0\n")));
+}
@@ -0,0 +1,140 @@
+mod common;
+mod serve_support;
+
+use std::net::TcpListener;
+
+use serve_support::{Server, closed_port};
+
+#[test]
+fn serve_reports_healthy_checks_with_200() {
+    let target = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = target.local_addr().unwrap().port();
+    let config = format!("tcp:name=local,host=127.0.0.1,port={port}\n");
+    let server = Server::start("healthy", &config, &["--interval", "100ms"]);
+
+    let (status, body) = server.wait_for("/health", |status, _| status == 200);
+    assert_eq!(status, 200, "{body}");
+    assert!(body.contains("Content-Type: application/json"));
+    assert!(body.contains("\"status\": \"healthy\""));
+    assert!(body.contains("\"name\": \"local\""));
+}
+
+#[test]
+fn serve_reports_failing_checks_with_503() {
+    let config = format!("tcp:name=down,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("failing", &config, &[]);
+
+    let (status, body) =
+        server.wait_for("/health", |_, body| !body.contains("pending: not run yet"));
+    assert_eq!(status, 503, "{body}");
+    assert!(body.contains("\"overall\": false"));
+    assert!(body.contains("Connection refused"), "{body}");
+}
+
+#[test]
+fn serve_rejects_unknown_paths_and_methods() {
+    let config = format!("tcp:host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("routes", &config, &[]);
+
+    assert_eq!(server.get("/nope").0, 404);
+    let (status, response) = server.request("DELETE", "/health");
+    assert_eq!(status, 405);
+    assert!(response.contains("Allow: GET, HEAD"));
+
+    let (status, response) = server.request("HEAD", "/health");
+    assert!(status == 200 || status == 503);
+    assert!(response.ends_with("\r\n\r\n"));
+}
+
+#[test]
+fn serve_exposes_prometheus_metrics() {
+    let config = format!(
+        "tcp:name=down,host=127.0.0.1,port={}\nhttp:name=web,url=http://127.0.0.1:{}\n",
+        closed_port(),
+        closed_port()
+    );
+    let server = Server::start("metrics", &config, &["--interval", "100ms"]);
+
+    let (status, body) = server.wait_for("/metrics", |_, body| {
+        body.contains("healthcheck_failures_total{name=\"down\",type=\"tcp\"} 2")
+    });
+    assert_eq!(status, 200);
+    assert!(body.contains("Content-Type: text/plain; version=0.0.4"));
+    assert!(body.contains("# TYPE healthcheck_up gauge"));
+    assert!(body.contains("healthcheck_up{name=\"web\",type=\"http\"} 0"));
+    assert!(body.contains("# TYPE healthcheck_latency_seconds histogram"));
+    assert!(
+        body.contains("healthcheck_latency_seconds_bucket{name=\"down\",type=\"tcp\",le=\"+Inf\"}")
+    );
+    assert!(body.contains("healthcheck_overall_up 0"));
+}
+
+#[test]
+fn serve_probe_endpoints_evaluate_their_own_checks() {
+    let alive = TcpListener::bind("127.0.0.1:0").unwrap();
+    let config = format!(
+        "tcp:name=self,host=127.0.0.1,port={},probe=liveness\ntcp:name=db,host=127.0.0.1,port={}\n",
+        alive.local_addr().unwrap().port(),
+        closed_port()
+    );
+    let server = Server::start("probes", &config, &["--interval", "100ms"]);
+
+    let (status, body) = server.wait_for("/livez", |status, _| status == 200);
+    assert_eq!(status, 200, "{body}");
+    assert!(!body.contains("\"name\": \"db\""));
+
+    // The database outage makes us unready, not dead
+    let (status, body) = server.wait_for("/readyz", |_, body| !body.contains("pending"));
+    assert_eq!(status, 503);
+    assert!(body.contains("\"name\": \"db\""));
+    assert!(!body.contains("\"name\": \"self\""));
+    assert_eq!(server.get("/startupz").0, 503);
+}
+
+#[test]
+fn serve_keeps_bounded_history_per_check() {
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start(
+        "history",
+        &config,
+        &["--interval", "50ms", "--history", "3"],
+    );
+
+    let (status, body) = server.wait_for("/checks/db/history", |_, body| {
+        body.matches("\"timestamp_ms\"").count() == 3
+    });
+    assert_eq!(status, 200, "{body}");
+    assert!(body.contains("\"capacity\": 3"));
+    assert!(body.contains("\"ok\": false"));
+    assert!(body.contains("refused"), "{body}");
+
+    // The buffer stays at capacity as runs continue
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    let (_, body) = server.get("/checks/db/history");
+    assert_eq!(body.matches("\"timestamp_ms\"").count(), 3, "{body}");
+
+    assert_eq!(server.get("/checks/missing/history").0, 404);
+    assert_eq!(server.request("POST", "/checks/db/history").0, 405);
+}
+
+#[test]
+fn serve_runs_checks_on_demand() {
+    let config = format!(
+        "tcp:name=db,host=127.0.0.1,port={},initial_delay_ms=600000\n",
+        closed_port()
+    );
+    let server = Server::start("on_demand", &config, &[]);
+    assert!(server.get("/health").1.contains("pending"));
+
+    let (status, body) = server.request("POST", "/checks/db/run");
+    assert_eq!(status, 503);
+    assert!(body.contains("refused"), "{body}");
+    assert!(!server.get("/health").1.contains("pending"));
+
+    let (status, body) = server.request("POST", "/checks/run");
+    assert_eq!(status, 503);
+    assert!(body.contains("\"name\": \"db\""), "{body}");
+
+    assert_eq!(server.get("/checks/db/run").0, 405);
+    assert_eq!(server.request("POST", "/checks/nope/run").0, 404);
+}
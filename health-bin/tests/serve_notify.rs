@@ -0,0 +1,80 @@
+mod common;
+mod serve_support;
+
+use std::env;
+use std::fs;
+use std::net::TcpListener;
+use std::os::unix::net::UnixDatagram;
+use std::time::Duration;
+
+use serve_support::{Server, closed_port};
+
+/// Datagrams received until one contains `wanted`
+fn receive_until(socket: &UnixDatagram, wanted: &str) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut buf = [0; 1024];
+    while let Ok(len) = socket.recv(&mut buf) {
+        messages.push(String::from_utf8_lossy(&buf[..len]).into_owned());
+        if messages.last().is_some_and(|m| m.contains(wanted)) {
+            break;
+        }
+    }
+    messages
+}
+
+fn notify_socket(name: &str) -> (UnixDatagram, String) {
+    let mut path = env::temp_dir();
+    path.push(format!("hc_notify_{name}_{}.sock", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let socket = UnixDatagram::bind(&path).unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    (socket, path.display().to_string())
+}
+
+#[test]
+fn serve_notifies_systemd_when_ready_and_pings_watchdog() {
+    let target = TcpListener::bind("127.0.0.1:0").unwrap();
+    let config = format!(
+        "tcp:name=db,host=127.0.0.1,port={}\n",
+        target.local_addr().unwrap().port()
+    );
+    let (socket, path) = notify_socket("ready");
+    let envs = [
+        ("NOTIFY_SOCKET", path.as_str()),
+        ("WATCHDOG_USEC", "400000"),
+    ];
+    let _server = Server::start_env("notify_ready", &config, &[], &envs);
+
+    let messages = receive_until(&socket, "WATCHDOG=1");
+    assert_eq!(messages.first().map(String::as_str), Some("READY=1"));
+    assert!(messages.contains(&"STATUS=healthy: all 1 checks passing".to_string()));
+    assert!(
+        messages.last().is_some_and(|m| m == "WATCHDOG=1"),
+        "{messages:?}"
+    );
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn serve_withholds_watchdog_while_critical_checks_fail() {
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let (socket, path) = notify_socket("failing");
+    let envs = [
+        ("NOTIFY_SOCKET", path.as_str()),
+        ("WATCHDOG_USEC", "200000"),
+    ];
+    let _server = Server::start_env("notify_failing", &config, &[], &envs);
+
+    let messages = receive_until(&socket, "STATUS=");
+    assert_eq!(messages[0], "READY=1");
+    assert_eq!(messages[1], "STATUS=unhealthy: 1 of 1 checks failing: db");
+
+    socket
+        .set_read_timeout(Some(Duration::from_millis(500)))
+        .unwrap();
+    let later = receive_until(&socket, "WATCHDOG=1");
+    assert!(!later.iter().any(|m| m == "WATCHDOG=1"), "{later:?}");
+    let _ = fs::remove_file(path);
+}
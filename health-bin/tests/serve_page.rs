@@ -0,0 +1,19 @@
+mod common;
+mod serve_support;
+
+use serve_support::{Server, closed_port};
+
+#[test]
+fn serve_renders_a_status_page() {
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let server = Server::start("page", &config, &["--interval", "50ms"]);
+
+    let (status, page) = server.wait_for("/", |_, page| page.contains("<svg"));
+    assert_eq!(status, 200, "{page}");
+    assert!(page.contains("Content-Type: text/html; charset=utf-8"));
+    assert!(page.contains("<td>db</td>"));
+    assert!(page.contains("<td class=\"failing\">failing</td>"));
+    assert!(page.contains("refused"), "{page}");
+    // Everything is inline
+    assert!(!page.contains("src=") && !page.contains("href="));
+}
@@ -0,0 +1,37 @@
+mod common;
+mod serve_support;
+
+use serve_support::{Server, closed_port};
+
+fn check(name: &str) -> String {
+    format!("tcp:name={name},host=127.0.0.1,port={}\n", closed_port())
+}
+
+#[test]
+fn serve_reloads_config_on_sighup() {
+    let server = Server::start("sighup", &check("old"), &["--interval", "100ms"]);
+    server.wait_for("/health", |_, body| body.contains("refused"));
+
+    server.write_config(&format!("{}{}", check("old"), check("new")));
+    server.signal("HUP");
+    let (_, body) = server.wait_for("/health", |_, body| body.contains("\"name\": \"new\""));
+    assert!(body.contains("\"name\": \"new\""), "{body}");
+
+    // An invalid config is rejected and the running checks stay
+    server.write_config("tcp:name=new,port=nope\n");
+    server.signal("HUP");
+    std::thread::sleep(std::time::Duration::from_millis(600));
+    let (_, body) = server.get("/health");
+    assert!(body.contains("\"name\": \"old\""), "{body}");
+    assert!(body.contains("\"name\": \"new\""), "{body}");
+}
+
+#[test]
+fn serve_reloads_changed_config_with_watch_config() {
+    let server = Server::start("watch_config", &check("old"), &["--watch-config"]);
+
+    server.write_config(&check("replaced"));
+    let (_, body) = server.wait_for("/health", |_, body| body.contains("\"name\": \"replaced\""));
+    assert!(body.contains("\"name\": \"replaced\""), "{body}");
+    assert!(!body.contains("\"name\": \"old\""), "{body}");
+}
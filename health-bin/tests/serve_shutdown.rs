@@ -0,0 +1,40 @@
+mod common;
+mod serve_support;
+
+use std::env;
+use std::time::{Duration, Instant};
+
+use serve_support::{Server, closed_port};
+
+#[test]
+fn serve_drains_on_sigterm_then_exits_cleanly() {
+    let config = format!(
+        "tcp:name=db,host=127.0.0.1,port={},probe=readiness\n",
+        closed_port()
+    );
+    let mut server = Server::start("drain", &config, &["--drain", "1s"]);
+    assert_eq!(server.get("/livez").0, 200);
+
+    let signalled = Instant::now();
+    server.signal("TERM");
+    let (status, body) = server.wait_for("/readyz", |_, body| body.contains("shutting down"));
+    assert_eq!(status, 503, "{body}");
+    assert_eq!(server.get("/health").0, 503);
+    assert_eq!(server.get("/livez").0, 200);
+
+    let exit = server.wait_exit().expect("server did not exit");
+    assert!(exit.success(), "{exit:?}");
+    assert!(signalled.elapsed() >= Duration::from_secs(1));
+}
+
+#[test]
+fn serve_removes_unix_socket_on_shutdown() {
+    let mut socket = env::temp_dir();
+    socket.push(format!("hc_shutdown_{}.sock", std::process::id()));
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let mut server = Server::start_unix("drain_unix", &config, &socket, &["--drain", "50ms"]);
+
+    server.signal("INT");
+    assert!(server.wait_exit().is_some_and(|exit| exit.success()));
+    assert!(!socket.exists());
+}
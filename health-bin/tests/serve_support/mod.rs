@@ -0,0 +1,144 @@
+//! Running `healthcheck serve` against a temporary config in tests
+
+// Each test crate uses a different subset of these helpers
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::common::get_healthcheck_bin;
+
+mod process;
+
+pub struct Server {
+    child: Child,
+    config_path: PathBuf,
+    pub addr: String,
+}
+
+impl Server {
+    /// Start `serve` on a free local port and wait until it accepts
+    pub fn start(name: &str, config: &str, args: &[&str]) -> Self {
+        Self::start_env(name, config, args, &[])
+    }
+
+    /// Like `start`, with extra environment variables for the server
+    pub fn start_env(name: &str, config: &str, args: &[&str], envs: &[(&str, &str)]) -> Self {
+        let addr = format!("127.0.0.1:{}", closed_port());
+        let server = Self::spawn(name, config, &addr, args, envs);
+        wait_until(|| TcpStream::connect(&server.addr).is_ok());
+        server
+    }
+
+    /// Start `serve` on the Unix socket `socket` and wait until it accepts;
+    /// `request` does not work on such a server
+    pub fn start_unix(name: &str, config: &str, socket: &Path, args: &[&str]) -> Self {
+        let listen = format!("unix:{}", socket.display());
+        let server = Self::spawn(name, config, &listen, args, &[]);
+        wait_until(|| UnixStream::connect(socket).is_ok());
+        server
+    }
+
+    fn spawn(name: &str, config: &str, listen: &str, args: &[&str], envs: &[(&str, &str)]) -> Self {
+        let mut config_path = env::temp_dir();
+        config_path.push(format!("serve_{name}_{}.conf", std::process::id()));
+        fs::write(&config_path, config).expect("failed to write config");
+
+        let child = Command::new(get_healthcheck_bin())
+            .arg("serve")
+            .args(["--listen", listen])
+            .args(args)
+            .arg(&config_path)
+            .env("RUST_LOG", "off")
+            .envs(envs.iter().copied())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("failed to start healthcheck serve");
+
+        Self {
+            child,
+            config_path,
+            addr: listen.to_string(),
+        }
+    }
+
+    /// Send a raw request and return the status code and full response
+    pub fn request(&self, method: &str, path: &str) -> (u16, String) {
+        self.request_with(method, path, &[])
+    }
+
+    /// Like `request`, with extra `Name: value` header lines
+    pub fn request_with(&self, method: &str, path: &str, headers: &[&str]) -> (u16, String) {
+        let mut stream = TcpStream::connect(&self.addr).expect("failed to connect");
+        let mut head = format!("{method} {path} HTTP/1.1\r\nHost: test\r\n");
+        for header in headers {
+            head.push_str(&format!("{header}\r\n"));
+        }
+        write!(stream, "{head}\r\n").unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response
+            .split(' ')
+            .nth(1)
+            .and_then(|code| code.parse().ok())
+            .expect("missing status code");
+        (status, response)
+    }
+
+    pub fn get(&self, path: &str) -> (u16, String) {
+        self.request("GET", path)
+    }
+
+    /// Replace the config file the server was started with
+    pub fn write_config(&self, config: &str) {
+        fs::write(&self.config_path, config).expect("failed to write config");
+    }
+
+    /// Poll `path` until `done` accepts the response
+    pub fn wait_for(&self, path: &str, done: impl Fn(u16, &str) -> bool) -> (u16, String) {
+        let start = Instant::now();
+        loop {
+            let (status, body) = self.get(path);
+            if done(status, &body) || start.elapsed() > Duration::from_secs(5) {
+                return (status, body);
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = fs::remove_file(&self.config_path);
+    }
+}
+
+/// A local port nothing listens on; closed ports make checks fail fast
+pub fn closed_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|l| l.local_addr())
+        .map(|a| a.port())
+        .expect("failed to find a free port")
+}
+
+fn wait_until(ready: impl Fn() -> bool) {
+    let start = Instant::now();
+    while !ready() {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "server did not start"
+        );
+        thread::sleep(Duration::from_millis(20));
+    }
+}
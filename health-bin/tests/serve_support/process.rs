@@ -0,0 +1,30 @@
+//! Signalling the server process and waiting for it to exit
+
+use std::process::{Command, ExitStatus};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::Server;
+
+impl Server {
+    /// Send a signal such as `HUP` to the server process
+    pub fn signal(&self, name: &str) {
+        let status = Command::new("kill")
+            .args(["-s", name, &self.child.id().to_string()])
+            .status()
+            .expect("failed to run kill");
+        assert!(status.success(), "kill -s {name} failed");
+    }
+
+    /// Wait up to five seconds for the server to exit on its own
+    pub fn wait_exit(&mut self) -> Option<ExitStatus> {
+        let start = Instant::now();
+        loop {
+            let status = self.child.try_wait().expect("failed to poll server");
+            if status.is_some() || start.elapsed() > Duration::from_secs(5) {
+                return status;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+}
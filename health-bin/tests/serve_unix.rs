@@ -0,0 +1,123 @@
+mod common;
+mod serve_support;
+
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use common::get_healthcheck_bin;
+use serve_support::{Server, closed_port};
+
+fn socket_path(name: &str) -> PathBuf {
+    let mut path = env::temp_dir();
+    path.push(format!("hc_{name}_{}.sock", std::process::id()));
+    path
+}
+
+fn run_from(socket: &Path) -> (Option<i32>, String) {
+    run_from_with(socket, &[])
+}
+
+fn run_from_with(socket: &Path, args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(get_healthcheck_bin())
+        .args(["--from", &format!("unix:{}", socket.display())])
+        .args(args)
+        .env("RUST_LOG", "off")
+        .output()
+        .expect("failed to run healthcheck --from");
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    )
+}
+
+#[test]
+fn serve_listens_on_unix_socket_with_mode() {
+    let socket = socket_path("mode");
+    let config = format!("tcp:name=db,host=127.0.0.1,port={}\n", closed_port());
+    let _server = Server::start_unix("unix_mode", &config, &socket, &["--socket-mode", "600"]);
+
+    let mode = fs::metadata(&socket).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    let mut stream = UnixStream::connect(&socket).unwrap();
+    write!(stream, "GET /health HTTP/1.1\r\nHost: test\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 503"), "{response}");
+    assert!(response.contains("\"name\": \"db\""));
+    let _ = fs::remove_file(socket);
+}
+
+#[test]
+fn from_reports_server_results_and_exit_code() {
+    let target = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = target.local_addr().unwrap().port();
+    let socket = socket_path("from");
+    let config = format!("tcp:name=local,host=127.0.0.1,port={port}\n");
+    let _server = Server::start_unix("unix_from", &config, &socket, &["--interval", "100ms"]);
+
+    let mut result = run_from(&socket);
+    for _ in 0..50 {
+        if result.0 == Some(0) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        result = run_from(&socket);
+    }
+    let (code, stdout) = result;
+    assert_eq!(code, Some(0), "{stdout}");
+    assert!(stdout.contains("\"status\": \"healthy\""));
+    assert!(stdout.contains("\"name\": \"local\""));
+    let _ = fs::remove_file(socket);
+}
+
+#[test]
+fn from_without_server_is_a_config_error() {
+    let (code, stdout) = run_from(&socket_path("missing"));
+    assert_eq!(code, Some(2));
+    assert!(stdout.contains("Failed to connect"), "{stdout}");
+}
+
+#[test]
+fn from_sends_token_and_treats_draining_as_unhealthy() {
+    let target = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = target.local_addr().unwrap().port();
+    let socket = socket_path("from_token");
+    let token = socket.with_extension("token");
+    fs::write(&token, "s3cret\n").unwrap();
+    let token_args = ["--token-file", token.to_str().unwrap()];
+    let config = format!("tcp:name=local,host=127.0.0.1,port={port}\n");
+    let args = [&token_args[..], &["--interval", "100ms", "--drain", "3s"]].concat();
+    let server = Server::start_unix("unix_from_token", &config, &socket, &args);
+
+    let mut result = run_from_with(&socket, &token_args);
+    for _ in 0..50 {
+        if result.0 == Some(0) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        result = run_from_with(&socket, &token_args);
+    }
+    assert_eq!(result.0, Some(0), "{}", result.1);
+    assert_eq!(run_from(&socket).0, Some(2));
+
+    server.signal("TERM");
+    let mut result = run_from_with(&socket, &token_args);
+    for _ in 0..50 {
+        if result.0 != Some(0) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        result = run_from_with(&socket, &token_args);
+    }
+    let (code, stdout) = result;
+    assert_eq!(code, Some(1), "{stdout}");
+    assert!(stdout.contains("shutting down"), "{stdout}");
+    let _ = fs::remove_file(token);
+}
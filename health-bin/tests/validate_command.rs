@@ -0,0 +1,66 @@
+mod common;
+
+use std::env;
+use std::fs;
+use std::process::{Command, Output};
+
+use common::get_healthcheck_bin;
+
+fn validate(file_name: &str, content: &str) -> Output {
+    let mut config_path = env::temp_dir();
+    config_path.push(file_name);
+    fs::write(&config_path, content).expect("failed to write config");
+
+    let output = Command::new(get_healthcheck_bin())
+        .arg("validate")
+        .arg(&config_path)
+        .output()
+        .expect("failed to execute healthcheck validate");
+
+    let _ = fs::remove_file(&config_path);
+    output
+}
+
+#[test]
+fn validate_accepts_valid_config_without_probing() {
+    // Port 1 is closed, but validate must not connect
+    let output = validate("validate_ok.conf", "tcp:host=127.0.0.1,port=1\n");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("OK (1 checks)"), "{stdout}");
+}
+
+#[test]
+fn validate_reports_every_problem_with_line_numbers() {
+    let config = "\
+# comment
+tcp:host=localhost
+unknown:foo=bar
+http:url=http://localhost:8080,retries=99
+not a check line
+";
+    let output = validate("validate_errors.conf", config);
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 4, "{stdout}");
+    assert!(lines[0].contains("line 2: missing required param: port"));
+    assert!(lines[1].contains("line 3: unknown check type: unknown"));
+    assert!(lines[2].contains("line 4: retries must be"));
+    assert!(lines[3].contains("line 5: missing ':' separator"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("4 problem(s)"), "{stderr}");
+}
+
+#[test]
+fn validate_fails_on_missing_file() {
+    let output = Command::new(get_healthcheck_bin())
+        .args(["validate", "/nonexistent/path/config.conf"])
+        .output()
+        .expect("failed to execute healthcheck validate");
+
+    assert!(!output.status.success());
+}
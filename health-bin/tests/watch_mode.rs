@@ -0,0 +1,70 @@
+mod common;
+
+use std::env;
+use std::fs;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use common::get_healthcheck_bin;
+
+#[test]
+fn watch_appends_plain_tables_when_not_a_terminal() {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|l| l.local_addr())
+        .unwrap()
+        .port();
+    let mut config = env::temp_dir();
+    config.push(format!("watch_plain_{}.conf", std::process::id()));
+    fs::write(&config, format!("tcp:name=db,host=127.0.0.1,port={port}\n")).unwrap();
+
+    let mut child = Command::new(get_healthcheck_bin())
+        .args(["watch", "--interval", "200ms"])
+        .arg(&config)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to start healthcheck watch");
+    thread::sleep(Duration::from_millis(900));
+    child.kill().unwrap();
+    let output = child.wait_with_output().unwrap();
+    let _ = fs::remove_file(&config);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains('\x1b'), "{stdout:?}");
+    assert!(stdout.matches("NAME  TYPE  STATE").count() >= 2, "{stdout}");
+    assert!(stdout.contains("UNHEALTHY"));
+    assert!(stdout.contains("db    tcp   failing"), "{stdout}");
+    assert!(stdout.contains("refused"), "{stdout}");
+}
+
+#[test]
+fn watch_reloads_the_config_on_sighup() {
+    let mut config = env::temp_dir();
+    config.push(format!("watch_reload_{}.conf", std::process::id()));
+    fs::write(&config, "tcp:name=old,host=127.0.0.1,port=1\n").unwrap();
+
+    let mut child = Command::new(get_healthcheck_bin())
+        .args(["watch", "--interval", "100ms"])
+        .arg(&config)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to start healthcheck watch");
+    thread::sleep(Duration::from_millis(300));
+
+    fs::write(&config, "tcp:name=new,host=127.0.0.1,port=1\n").unwrap();
+    let pid = child.id().to_string();
+    let kill = Command::new("kill").args(["-s", "HUP", &pid]).status();
+    assert!(kill.unwrap().success());
+    thread::sleep(Duration::from_millis(600));
+
+    assert!(child.try_wait().unwrap().is_none(), "SIGHUP ended watch");
+    child.kill().unwrap();
+    let output = child.wait_with_output().unwrap();
+    let _ = fs::remove_file(&config);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let last = stdout.rsplit("[+").next().unwrap_or_default();
+    assert!(last.contains("new ") && !last.contains("old "), "{stdout}");
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+use super::names::name_problems;
+
 // Simple config format:
 // # comment
 // type:param1=value1,param2=value2
@@ -9,12 +11,36 @@ use std::path::Path;
 // Example:
 // tcp:host=localhost,port=21116,timeout_ms=2000
 // http:url=http://localhost:12008
+//
+// Any line may carry `name=` to label the check in status output:
+// tcp:name=postgres,host=db,port=5432
+//
+// Run-wide options use the reserved `settings` type:
+// settings:deadline_ms=5000,concurrency=4
+
+/// Reserved check type holding run-wide settings instead of a probe
+pub const SETTINGS_TYPE: &str = "settings";
 
 /// Individual check configuration
 #[derive(Debug, Clone)]
 pub struct CheckConfig {
     pub check_type: String,
     pub params: HashMap<String, String>,
+    /// 1-based line number in the source config
+    pub line: usize,
+}
+
+impl CheckConfig {
+    /// Explicit check name from `name=`, if one was given
+    ///
+    /// Process checks historically use `name=` for the process itself; for
+    /// them `name=` is only a check name when `process=` is also present.
+    pub fn label(&self) -> Option<&str> {
+        if self.check_type == "process" && !self.params.contains_key("process") {
+            return None;
+        }
+        self.params.get("name").map(String::as_str)
+    }
 }
 
 /// Parse a config file into check configurations
@@ -25,7 +51,20 @@ pub fn parse_config_file<P: AsRef<Path>>(path: P) -> Result<Vec<CheckConfig>, St
 
 /// Parse config from string
 pub fn parse_config_str(content: &str) -> Result<Vec<CheckConfig>, String> {
+    let (checks, mut problems) = parse_config_lenient(content);
+    match problems.is_empty() {
+        true => Ok(checks),
+        false => Err(problems.swap_remove(0)),
+    }
+}
+
+/// Parse config from string, collecting every problem instead of stopping
+/// at the first one
+///
+/// Lines with syntax errors are left out of the returned checks.
+pub fn parse_config_lenient(content: &str) -> (Vec<CheckConfig>, Vec<String>) {
     let mut checks = Vec::new();
+    let mut problems = Vec::new();
 
     for (line_num, line) in content.lines().enumerate() {
         let line = line.trim();
@@ -36,19 +75,41 @@ pub fn parse_config_str(content: &str) -> Result<Vec<CheckConfig>, String> {
         }
 
         // Split type:params
-        let (check_type, params_str) = line
-            .split_once(':')
-            .ok_or_else(|| format!("line {}: missing ':' separator", line_num + 1))?;
+        let Some((check_type, params_str)) = line.split_once(':') else {
+            problems.push(format!("line {}: missing ':' separator", line_num + 1));
+            continue;
+        };
 
-        let params = parse_params(params_str, line_num + 1)?;
+        match parse_params(params_str, line_num + 1) {
+            Ok(params) => checks.push(CheckConfig {
+                check_type: check_type.trim().to_string(),
+                params,
+                line: line_num + 1,
+            }),
+            Err(e) => problems.push(e),
+        }
+    }
 
-        checks.push(CheckConfig {
-            check_type: check_type.trim().to_string(),
-            params,
-        });
+    problems.extend(name_problems(&checks));
+    (checks, problems)
+}
+
+/// Separate `settings:` lines from probe lines
+///
+/// Later settings lines override keys from earlier ones.
+pub fn split_settings(configs: Vec<CheckConfig>) -> (HashMap<String, String>, Vec<CheckConfig>) {
+    let mut settings = HashMap::new();
+    let mut checks = Vec::with_capacity(configs.len());
+
+    for config in configs {
+        if config.check_type == SETTINGS_TYPE {
+            settings.extend(config.params);
+        } else {
+            checks.push(config);
+        }
     }
 
-    Ok(checks)
+    (settings, checks)
 }
 
 /// Parse param1=value1,param2=value2 into HashMap
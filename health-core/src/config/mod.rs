@@ -2,10 +2,15 @@ mod database;
 mod file;
 mod helpers;
 mod http;
+mod names;
 
 pub use database::DbConfig;
-pub use file::{CheckConfig, parse_config_file, parse_config_str};
+pub use file::{
+    CheckConfig, SETTINGS_TYPE, parse_config_file, parse_config_lenient, parse_config_str,
+    split_settings,
+};
 pub use http::HttpConfig;
+pub use names::validate_check_name;
 
 pub const DEFAULT_HTTP_URL: &str = "http://localhost:12008";
 pub const DEFAULT_HTTP_TIMEOUT_MS: u64 = 5_000;
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use super::CheckConfig;
+
+/// Validate an explicit check name
+///
+/// Names appear in JSON output and URL paths, so they are limited to
+/// ASCII letters, digits and `-_.:`.
+pub fn validate_check_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("check name cannot be empty".to_string());
+    }
+
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !"-_.:".contains(*c))
+    {
+        return Err(format!("invalid character '{c}' in check name '{name}'"));
+    }
+
+    Ok(())
+}
+
+/// Describe every invalid or duplicate explicit check name
+pub(super) fn name_problems(checks: &[CheckConfig]) -> Vec<String> {
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    let mut problems = Vec::new();
+
+    for check in checks {
+        let Some(name) = check.label() else {
+            continue;
+        };
+
+        if let Err(e) = validate_check_name(name) {
+            problems.push(format!("line {}: {e}", check.line));
+        } else if let Some(&first) = seen.get(name) {
+            problems.push(format!(
+                "line {}: duplicate check name '{name}' (first defined on line {first})",
+                check.line
+            ));
+        } else {
+            seen.insert(name, check.line);
+        }
+    }
+
+    problems
+}
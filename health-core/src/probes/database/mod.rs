@@ -4,11 +4,15 @@ use super::{ProbeResult, elapsed_ms};
 use log::{error, info};
 use std::collections::HashMap;
 use std::time::Instant;
+use target::redacted_target;
+
+mod target;
 
 /// PostgreSQL database check
 pub struct DatabaseCheck {
     conn_str: String,
     timeout_ms: u64,
+    target: String,
 }
 
 impl DatabaseCheck {
@@ -22,6 +26,7 @@ impl DatabaseCheck {
                 .unwrap_or(3000);
 
             return Ok(Box::new(Self {
+                target: redacted_target(conn_str),
                 conn_str: conn_str.clone(),
                 timeout_ms,
             }));
@@ -77,6 +82,7 @@ impl DatabaseCheck {
         Ok(Box::new(Self {
             conn_str,
             timeout_ms,
+            target: format!("{host}:{port}/{dbname}"),
         }))
     }
 }
@@ -89,6 +95,10 @@ impl HealthCheck for DatabaseCheck {
     fn name(&self) -> &str {
         "database"
     }
+
+    fn target(&self) -> &str {
+        &self.target
+    }
 }
 
 fn check_database(conn_str: &str, timeout_ms: u64) -> ProbeResult {
@@ -0,0 +1,19 @@
+use postgres::config::Host;
+
+/// Describe a connection string as `host:port/dbname` without credentials
+pub fn redacted_target(conn_str: &str) -> String {
+    let Ok(config) = conn_str.parse::<postgres::Config>() else {
+        return "invalid connection string".to_string();
+    };
+
+    let host = match config.get_hosts().first() {
+        Some(Host::Tcp(host)) => host.clone(),
+        #[cfg(unix)]
+        Some(Host::Unix(path)) => path.display().to_string(),
+        None => "localhost".to_string(),
+    };
+    let port = config.get_ports().first().copied().unwrap_or(5432);
+    let dbname = config.get_dbname().unwrap_or("postgres");
+
+    format!("{host}:{port}/{dbname}")
+}
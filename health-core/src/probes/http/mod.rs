@@ -10,11 +10,13 @@ mod transport;
 mod url;
 
 use transport::perform_request;
-use url::parse_http_url;
+use url::{parse_http_url, redacted_url};
 
 /// HTTP endpoint check
 pub struct HttpCheck {
     config: HttpConfig,
+    /// URL without userinfo or query, safe to show in status output
+    target: String,
 }
 
 impl HttpCheck {
@@ -31,6 +33,7 @@ impl HttpCheck {
             .unwrap_or(5000);
 
         Ok(Box::new(Self {
+            target: redacted_url(&url),
             config: HttpConfig::new(url, Duration::from_millis(timeout_ms)),
         }))
     }
@@ -44,6 +47,10 @@ impl HealthCheck for HttpCheck {
     fn name(&self) -> &str {
         "http"
     }
+
+    fn target(&self) -> &str {
+        &self.target
+    }
 }
 
 pub fn check_http(config: &HttpConfig) -> ProbeResult {
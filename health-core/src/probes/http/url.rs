@@ -6,6 +6,23 @@ pub struct HttpTarget {
     pub path: String,
 }
 
+/// Describe a URL as `scheme://host:port/path`, dropping any `user:pass@`
+/// userinfo, query string and fragment, which may carry credentials
+pub fn redacted_url(url: &str) -> String {
+    let (scheme, rest) = url.split_once("://").unwrap_or(("", url));
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    let rest = &rest[..end];
+
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    match scheme {
+        "" => format!("{host}{path}"),
+        scheme => format!("{scheme}://{host}{path}"),
+    }
+}
+
 pub fn parse_http_url(url: &str) -> Result<HttpTarget, String> {
     let lower = url.to_ascii_lowercase();
     if !lower.starts_with("http://") {
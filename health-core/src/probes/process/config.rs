@@ -8,10 +8,14 @@ pub struct ProcessConfig {
 
 impl ProcessConfig {
     /// Parse from config parameters
+    ///
+    /// The process is given by `process=`; the legacy `name=` is used when
+    /// `process=` is absent, in which case `name=` is not a check name.
     pub fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
         let name = params
-            .get("name")
-            .ok_or("missing required param: name")?
+            .get("process")
+            .or_else(|| params.get("name"))
+            .ok_or("missing required param: process")?
             .clone();
 
         if name.is_empty() {
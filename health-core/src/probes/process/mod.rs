@@ -49,6 +49,10 @@ impl HealthCheck for ProcessCheck {
     fn name(&self) -> &str {
         "process"
     }
+
+    fn target(&self) -> &str {
+        self.config.name()
+    }
 }
 
 /// Check if a process is running by reading /proc
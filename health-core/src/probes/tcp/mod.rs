@@ -12,22 +12,24 @@ use std::time::Instant;
 /// TCP port connectivity check
 pub struct TcpCheck {
     config: TcpConfig,
+    target: String,
 }
 
 impl TcpCheck {
     /// Create from config parameters
     pub fn from_params(params: &HashMap<String, String>) -> Result<Box<dyn HealthCheck>, String> {
         let config = TcpConfig::from_params(params)?;
-        Ok(Box::new(Self { config }))
+        let target = format!("{}:{}", config.host(), config.port());
+        Ok(Box::new(Self { config, target }))
     }
 }
 
 impl HealthCheck for TcpCheck {
     fn check(&self) -> ProbeResult {
         let start = Instant::now();
-        let addr = format!("{}:{}", self.config.host(), self.config.port());
+        let addr = &self.target;
 
-        match perform_tcp_check(&addr, self.config.timeout()) {
+        match perform_tcp_check(addr, self.config.timeout()) {
             Ok(_) => {
                 let latency = elapsed_ms(start);
                 info!("TCP check succeeded for {addr} in {latency}ms");
@@ -44,6 +46,10 @@ impl HealthCheck for TcpCheck {
     fn name(&self) -> &str {
         "tcp"
     }
+
+    fn target(&self) -> &str {
+        &self.target
+    }
 }
 
 fn perform_tcp_check(addr: &str, timeout: std::time::Duration) -> Result<(), String> {
@@ -8,6 +8,13 @@ pub trait HealthCheck: Send + Sync {
 
     /// Get the name/type of this check
     fn name(&self) -> &str;
+
+    /// Describe what is being probed (address, URL, process name)
+    ///
+    /// Must not contain credentials; it is shown in status output.
+    fn target(&self) -> &str {
+        ""
+    }
 }
 
 /// Type alias for check factory functions
@@ -1,4 +1,4 @@
-use healthcheck_core::config::parse_config_str;
+use healthcheck_core::config::{parse_config_lenient, parse_config_str, split_settings};
 
 #[test]
 fn parse_config_str_valid_tcp() {
@@ -66,3 +66,78 @@ fn parse_config_str_database() {
     assert_eq!(configs.len(), 1);
     assert_eq!(configs[0].check_type, "database");
 }
+
+#[test]
+fn split_settings_separates_settings_lines() {
+    let config = "\
+settings:deadline_ms=5000
+tcp:host=127.0.0.1,port=22
+settings:concurrency=2,deadline_ms=3000
+";
+    let (settings, checks) = split_settings(parse_config_str(config).unwrap());
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].check_type, "tcp");
+    assert_eq!(
+        settings.get("deadline_ms").map(String::as_str),
+        Some("3000")
+    );
+    assert_eq!(settings.get("concurrency").map(String::as_str), Some("2"));
+}
+
+#[test]
+fn parse_config_str_records_line_numbers() {
+    let config = "# header\n\ntcp:port=22\nhttp:url=http://localhost\n";
+    let configs = parse_config_str(config).unwrap();
+    assert_eq!(configs[0].line, 3);
+    assert_eq!(configs[1].line, 4);
+}
+
+#[test]
+fn parse_config_str_rejects_duplicate_names() {
+    let config = "\
+tcp:name=db,port=5432
+tcp:name=db,port=6432
+";
+    let err = parse_config_str(config).unwrap_err();
+    assert!(err.contains("line 2"), "{err}");
+    assert!(err.contains("duplicate check name 'db'"), "{err}");
+}
+
+#[test]
+fn parse_config_str_rejects_invalid_names() {
+    assert!(parse_config_str("tcp:name=a b,port=22").is_err());
+    assert!(parse_config_str("tcp:name=a/b,port=22").is_err());
+    assert!(parse_config_str("tcp:name=db-1.primary:main,port=22").is_ok());
+}
+
+#[test]
+fn check_config_label_skips_legacy_process_name() {
+    let configs = parse_config_str(
+        "\
+process:name=nginx
+process:name=web,process=nginx
+process:name=nginx
+",
+    );
+    // Legacy `name=` is the process name, so repeating it is not a duplicate
+    let configs = configs.unwrap();
+    assert_eq!(configs[0].label(), None);
+    assert_eq!(configs[1].label(), Some("web"));
+}
+
+#[test]
+fn parse_config_lenient_collects_every_problem() {
+    let config = "\
+tcp:name=db,port=5432
+no separator here
+tcp:name=db,port=6432
+http:url
+tcp:name=cache,port=6379
+";
+    let (checks, problems) = parse_config_lenient(config);
+    assert_eq!(checks.len(), 3);
+    assert_eq!(problems.len(), 3, "{problems:?}");
+    assert!(problems[0].starts_with("line 2:"), "{problems:?}");
+    assert!(problems[1].starts_with("line 4:"), "{problems:?}");
+    assert!(problems[2].starts_with("line 3: duplicate"), "{problems:?}");
+}
@@ -48,3 +48,13 @@ fn process_check_nonexistent_fails() {
     let result = check.check();
     assert!(!result.ok);
 }
+
+#[test]
+fn process_check_target_prefers_process_param() {
+    let mut params = HashMap::new();
+    params.insert("name".to_string(), "web".to_string());
+    params.insert("process".to_string(), "nginx".to_string());
+
+    let check = ProcessCheck::from_params(&params).unwrap();
+    assert_eq!(check.target(), "nginx");
+}
@@ -63,3 +63,13 @@ fn tcp_check_invalid_port_fails() {
     let result = check.check();
     assert!(!result.ok);
 }
+
+#[test]
+fn tcp_check_target() {
+    let mut params = HashMap::new();
+    params.insert("host".to_string(), "db.internal".to_string());
+    params.insert("port".to_string(), "5432".to_string());
+
+    let check = TcpCheck::from_params(&params).unwrap();
+    assert_eq!(check.target(), "db.internal:5432");
+}